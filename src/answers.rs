@@ -0,0 +1,59 @@
+use crate::input;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Name of the answers file in the data directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known-good answers, keyed by (year, day, part).
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, u32, usize), String>,
+}
+
+impl Answers {
+    /// Parse answers from text where each line has the format
+    /// "<year> <day> <part> <answer>". Blank lines and lines starting with '#'
+    /// are ignored.
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Invalid answer on line {}: {}", i + 1, line);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if words.len() != 4 {
+                return Err(invalid());
+            }
+            let year: u32 = words[0].parse().map_err(|_| invalid())?;
+            let day: u32 = words[1].parse().map_err(|_| invalid())?;
+            let part: usize = words[2].parse().map_err(|_| invalid())?;
+
+            if answers.insert((year, day, part), words[3].to_string()).is_some() {
+                return Err(format!("Duplicate answer on line {}: {}", i + 1, line));
+            }
+        }
+
+        return Ok(Self { answers });
+    }
+
+    /// Read answers from the given file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let data = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        return Self::parse(&data);
+    }
+
+    /// Returns the known answer for the given puzzle part, if any.
+    pub fn get(&self, year: u32, day: u32, part: usize) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|s| s.as_str())
+    }
+}
+
+/// Returns the path of the answers file in the data directory.
+pub fn default_path() -> PathBuf {
+    input::data_dir().join(ANSWERS_FILE)
+}
@@ -0,0 +1,171 @@
+use crate::error::AocError;
+use crate::registry::{PartFn, Solver};
+use regex::Regex;
+use std::time::{Duration, Instant};
+
+/// Summary statistics of the wall times of repeated runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Compute statistics from a non-empty list of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Expected at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        return Self {
+            min: sorted[0],
+            median,
+            max: sorted[n - 1],
+        };
+    }
+}
+
+/// Benchmark result of one stage of a solver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchResult {
+    pub year: u32,
+    pub day: u32,
+    /// One of "parse", "part1" or "part2"
+    pub stage: String,
+    pub stats: Stats,
+}
+
+/// Call `f` the given number of times, and return the wall time statistics.
+pub fn time<R>(iterations: usize, mut f: impl FnMut() -> R) -> Stats {
+    let mut samples: Vec<Duration> = Vec::new();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        // do not count the time taken to drop the result
+        drop(result);
+    }
+    return Stats::from_samples(&samples);
+}
+
+/// Run the parse, part 1 and part 2 stages of a solver repeatedly on the given
+/// input, and return the statistics of each stage. If `part` is given, only
+/// that part is run after parsing. Returns an error if any stage fails.
+pub fn bench_solver(
+    solver: &Solver,
+    data: &str,
+    part: Option<usize>,
+    iterations: usize,
+) -> Result<Vec<BenchResult>, AocError> {
+    let result = |stage: &str, stats: Stats| BenchResult {
+        year: solver.year,
+        day: solver.day,
+        stage: stage.to_string(),
+        stats,
+    };
+
+    let parts: Vec<(usize, PartFn)> = (1..)
+        .zip(solver.parts)
+        .filter(|&(part_number, _)| part.is_none_or(|p| p == part_number))
+        .collect();
+
+    // run each stage once first, so that errors are reported before timing
+    let input = (solver.parse)(data)?;
+    for (_, run_part) in &parts {
+        run_part(input.as_ref())?;
+    }
+
+    let mut results = vec![result("parse", time(iterations, || (solver.parse)(data)))];
+    for (part_number, run_part) in &parts {
+        let stats = time(iterations, || run_part(input.as_ref()));
+        results.push(result(&format!("part{}", part_number), stats));
+    }
+    return Ok(results);
+}
+
+/// Serialize benchmark results to JSON. Times are stored in nanoseconds.
+pub fn to_json(results: &[BenchResult]) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"stage\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                r.year,
+                r.day,
+                r.stage,
+                r.stats.min.as_nanos(),
+                r.stats.median.as_nanos(),
+                r.stats.max.as_nanos()
+            )
+        })
+        .collect();
+    return format!("[\n{}\n]\n", objects.join(",\n"));
+}
+
+/// Deserialize benchmark results from the JSON written by `to_json`.
+pub fn from_json(json: &str) -> Result<Vec<BenchResult>, String> {
+    let object_re = Regex::new(r"\{[^{}]*\}").unwrap();
+    let field_re = Regex::new(r#""(\w+)"\s*:\s*(?:"([^"]*)"|(\d+))"#).unwrap();
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    for object in object_re.find_iter(json) {
+        let mut year = None;
+        let mut day = None;
+        let mut stage = None;
+        let mut nanos = [None; 3];
+
+        for cap in field_re.captures_iter(object.as_str()) {
+            let string = cap.get(2).map(|m| m.as_str());
+            let number = cap.get(3).and_then(|m| m.as_str().parse::<u64>().ok());
+            match &cap[1] {
+                "year" => year = number.map(|n| n as u32),
+                "day" => day = number.map(|n| n as u32),
+                "stage" => stage = string.map(|s| s.to_string()),
+                "min_ns" => nanos[0] = number,
+                "median_ns" => nanos[1] = number,
+                "max_ns" => nanos[2] = number,
+                _ => {}
+            }
+        }
+
+        let invalid = || format!("Invalid benchmark result: {}", object.as_str());
+        let duration = |n: Option<u64>| n.map(Duration::from_nanos).ok_or_else(invalid);
+        results.push(BenchResult {
+            year: year.ok_or_else(invalid)?,
+            day: day.ok_or_else(invalid)?,
+            stage: stage.ok_or_else(invalid)?,
+            stats: Stats {
+                min: duration(nanos[0])?,
+                median: duration(nanos[1])?,
+                max: duration(nanos[2])?,
+            },
+        });
+    }
+    return Ok(results);
+}
+
+/// If the median time of `result` is slower than that of the matching result
+/// in `baseline` by more than `threshold` (e.g. 0.1 for 10%), returns the
+/// relative slowdown.
+pub fn find_regression(result: &BenchResult, baseline: &[BenchResult], threshold: f64) -> Option<f64> {
+    let base = baseline
+        .iter()
+        .find(|b| b.year == result.year && b.day == result.day && b.stage == result.stage)?;
+    let base_median = base.stats.median.as_secs_f64();
+    if base_median == 0.0 {
+        return None;
+    }
+    let slowdown = result.stats.median.as_secs_f64() / base_median - 1.0;
+    if slowdown > threshold {
+        return Some(slowdown);
+    }
+    return None;
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Error raised when the puzzle input is malformed. Carries as much of the
+/// location of the offending text as is known, so that the diagnostic points
+/// at exactly what could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    /// File containing the offending text
+    pub file: Option<String>,
+    /// Line number of the offending text, starting from 1
+    pub line: Option<usize>,
+    /// Column number of the offending text, starting from 1
+    pub column: Option<usize>,
+    /// The offending text itself
+    pub text: Option<String>,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    /// Create an error for the given offending text.
+    pub fn invalid(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Self::new(message)
+        }
+    }
+
+    /// Set the column of the offending text, if not already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        return self;
+    }
+
+    /// Set the line containing the offending text, if not already known. If
+    /// the column is not known, it is found by searching for the offending
+    /// text in the line.
+    pub fn at_line(mut self, line: usize, line_text: &str) -> Self {
+        if self.line.is_some() {
+            return self;
+        }
+        self.line = Some(line);
+        if self.column.is_none() {
+            if let Some(text) = &self.text {
+                self.column = line_text
+                    .find(text.as_str())
+                    .map(|i| line_text[..i].chars().count() + 1);
+            }
+        }
+        return self;
+    }
+
+    /// Set the file containing the offending text, if not already known.
+    pub fn in_file(mut self, file: impl fmt::Display) -> Self {
+        self.file.get_or_insert_with(|| file.to_string());
+        return self;
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // location has the form "<file>:<line>:<column>: "
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+            if let Some(column) = self.column {
+                write!(f, "{}:", column)?;
+            }
+        }
+        if self.file.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, ": {:?}", text)?;
+        }
+        return Ok(());
+    }
+}
+
+impl std::error::Error for AocError {}
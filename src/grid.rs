@@ -0,0 +1,258 @@
+use crate::error::AocError;
+use crate::input::Reader;
+use crate::vec::{Rect, Vec2d};
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, stored row by row in a single buffer. Cells
+/// are addressed by `Vec2d { x, y }`, where `x` is the column and `y` is the
+/// row, both starting from 0 at the top-left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Create a grid from cells listed row by row. Panics if the number of
+    /// cells does not match the dimensions.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Number of cells does not match grid size");
+        Self { width, height, cells }
+    }
+
+    /// Parse a grid from a character map, e.g. the puzzle input, mapping each
+    /// character to a cell with `f`. Every row must have the same length.
+    pub fn parse(data: &str, mut f: impl FnMut(char) -> Result<T, AocError>) -> Result<Self, AocError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells: Vec<T> = Vec::new();
+
+        for (i, line) in Reader::new(data).lines().enumerate() {
+            let mut row_width = 0;
+            for (j, c) in line.chars().enumerate() {
+                let cell = f(c).map_err(|e| e.at_column(j + 1).at_line(i + 1, line))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            if i == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(AocError::new(format!("Expected all rows to have length {}", width)).at_line(i + 1, line));
+            }
+            height += 1;
+        }
+
+        return Ok(Self { width, height, cells });
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the rectangle of all positions in the grid.
+    pub fn bounds(&self) -> Rect {
+        Rect::from_size(self.width, self.height)
+    }
+
+    /// Returns the index of the given position in the buffer, or `None` if
+    /// it is out of bounds.
+    fn index_of(&self, pos: Vec2d) -> Option<usize> {
+        let (x, y) = pos.to_index(self.width, self.height)?;
+        return Some(y * self.width + x);
+    }
+
+    /// Returns true if the position is inside the grid.
+    pub fn contains(&self, pos: Vec2d) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    /// Returns the cell at the given position, or `None` if it is out of bounds.
+    pub fn get(&self, pos: Vec2d) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at the given position, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, pos: Vec2d) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the cells of the given row.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the cells of the given column, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Returns the columns of the grid, from left to right.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns all positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2d> {
+        self.bounds().points()
+    }
+
+    /// Returns all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2d, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Vec2d> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2d) -> &Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("Position {} out of bounds", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2d> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2d) -> &mut Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("Position {} out of bounds", pos),
+        }
+    }
+}
+
+/// A grid over the unbounded plane that only stores the cells that have been
+/// set. Unset cells have a default value. The bounding rectangle of the set
+/// cells grows as cells are set, so that the grid can be converted to a dense
+/// `Grid` for rendering or analysis.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2d, T>,
+    default: T,
+    bounds: Rect,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create an empty grid where every cell has the value `default`.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: Rect::default(),
+        }
+    }
+
+    /// Create a grid from a dense grid, setting every cell. The top-left
+    /// corner of `grid` is placed at `origin`.
+    pub fn from_grid(grid: &Grid<T>, origin: Vec2d, default: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse = Self::new(default);
+        for (pos, value) in grid.iter() {
+            sparse.insert(pos + origin, value.clone());
+        }
+        // keep the full extent, even if the grid is empty along one axis
+        sparse.bounds = Rect::new(origin, origin + grid.bounds().max);
+        return sparse;
+    }
+
+    /// Returns the smallest rectangle containing every set cell.
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Returns the number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns true if the cell has been set.
+    pub fn is_set(&self, pos: Vec2d) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Returns the value of the cell, or the default value if it is unset.
+    pub fn get(&self, pos: Vec2d) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Returns the value of the cell, setting it to the default value first
+    /// if it is unset.
+    pub fn get_mut(&mut self, pos: Vec2d) -> &mut T
+    where
+        T: Clone,
+    {
+        self.bounds = self.bounds.include(pos);
+        self.cells.entry(pos).or_insert_with(|| self.default.clone())
+    }
+
+    /// Set the cell, returning its previous value if it was set.
+    pub fn insert(&mut self, pos: Vec2d, value: T) -> Option<T> {
+        self.bounds = self.bounds.include(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Returns the set cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2d, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Returns a dense grid covering the bounding rectangle, with unset cells
+    /// filled with the default value. Position `pos` in the returned grid
+    /// corresponds to `pos + self.bounds().min` in this one.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.bounds.points().map(|pos| self.get(pos).clone()).collect();
+        return Grid::from_vec(self.bounds.width() as usize, self.bounds.height() as usize, cells);
+    }
+}
+
+impl<T> Index<Vec2d> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2d) -> &Self::Output {
+        self.get(pos)
+    }
+}
@@ -0,0 +1,202 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use std::fmt;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Environment variable overriding the input file of a solver
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Environment variable overriding the directory containing puzzle data
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Where a solver reads its puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Parse a source from a command line argument, where "-" denotes stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            _ => Source::File(PathBuf::from(arg)),
+        }
+    }
+
+    /// Returns the source given by the `data/` convention, e.g.
+    /// "data/22day7.txt" for 2022 day 7.
+    pub fn default_for(year: u32, day: u32) -> Self {
+        return Source::File(data_dir().join(format!("{}day{}.txt", year % 100, day)));
+    }
+
+    /// Read the entire puzzle input.
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut data = String::new();
+                std::io::stdin().read_to_string(&mut data)?;
+                Ok(data)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Returns the directory containing puzzle data. This can be set with the
+/// `AOC_DATA_DIR` environment variable, and otherwise is found relative to the
+/// crate root so solvers can be run from any directory.
+pub fn data_dir() -> PathBuf {
+    match std::env::var_os(DATA_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data"),
+    }
+}
+
+/// Returns the user-specified input source, if any. The command line argument
+/// takes precedence over the `AOC_INPUT` environment variable.
+pub fn override_source(arg: Option<&str>) -> Option<Source> {
+    if let Some(arg) = arg {
+        return Some(Source::from_arg(arg));
+    }
+    let var = std::env::var(INPUT_VAR).ok()?;
+    return Some(Source::from_arg(&var));
+}
+
+/// A block of consecutive non-blank lines of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Paragraph<'a> {
+    /// Line number of the first line in the input, starting from 1
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+/// Reads puzzle input with normalized line endings and whitespace, so that
+/// downloaded files work unchanged. Lines may end in "\n" or "\r\n", trailing
+/// whitespace is removed from every line, and blank lines at the end of the
+/// input are dropped.
+#[derive(Clone, Debug)]
+pub struct Reader<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a str) -> Self {
+        let mut lines: Vec<&str> = data.lines().map(|line| line.trim_end()).collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        return Self { lines };
+    }
+
+    /// Returns the normalized lines of the input.
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.lines.iter().copied()
+    }
+
+    /// Returns the blocks of lines separated by blank lines. Repeated blank
+    /// lines, and blank lines at the start or end of the input, never produce
+    /// an empty paragraph.
+    pub fn paragraphs(&self) -> impl Iterator<Item = Paragraph<'a>> + '_ {
+        let mut start = 0;
+        std::iter::from_fn(move || {
+            // skip separating blank lines
+            while start < self.lines.len() && self.lines[start].is_empty() {
+                start += 1;
+            }
+            if start == self.lines.len() {
+                return None;
+            }
+
+            let end = match self.lines[start..].iter().position(|line| line.is_empty()) {
+                Some(length) => start + length,
+                None => self.lines.len(),
+            };
+            let paragraph = Paragraph {
+                line: start + 1,
+                lines: self.lines[start..end].to_vec(),
+            };
+            start = end;
+            return Some(paragraph);
+        })
+    }
+
+    /// Returns the characters of the input as a grid, e.g. for a map of the
+    /// puzzle. Returns an error if the lines have different lengths.
+    pub fn grid(&self) -> Result<Grid<char>, AocError> {
+        Grid::parse(&self.text(), Ok)
+    }
+
+    /// Returns the normalized input as a single string.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_normalized() {
+        let reader = Reader::new("a b  \r\n\r\n  c\t\r\nd\r\n\r\n\n  \n");
+        let lines: Vec<&str> = reader.lines().collect();
+        // leading whitespace and inner blank lines are kept
+        assert_eq!(lines, ["a b", "", "  c", "d"]);
+        assert_eq!(reader.text(), "a b\n\n  c\nd");
+    }
+
+    #[test]
+    fn crlf_reads_like_lf() {
+        let lf = "[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+        let crlf = lf.replace('\n', "\r\n");
+        assert!(Reader::new(lf).lines().eq(Reader::new(&crlf).lines()));
+        assert_eq!(Reader::new(lf).text(), Reader::new(&crlf).text());
+    }
+
+    #[test]
+    fn empty_input_has_no_lines() {
+        assert_eq!(Reader::new("").lines().count(), 0);
+        assert_eq!(Reader::new("\n\r\n  \n").lines().count(), 0);
+    }
+
+    #[test]
+    fn paragraphs_have_line_numbers() {
+        let reader = Reader::new("\n\na\nb\n\n\n\nc\r\n\r\nd\ne\n\n");
+        let paragraphs: Vec<Paragraph> = reader.paragraphs().collect();
+        assert_eq!(
+            paragraphs,
+            [
+                Paragraph {
+                    line: 3,
+                    lines: vec!["a", "b"]
+                },
+                Paragraph {
+                    line: 8,
+                    lines: vec!["c"]
+                },
+                Paragraph {
+                    line: 10,
+                    lines: vec!["d", "e"]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_input_has_no_paragraphs() {
+        assert_eq!(Reader::new("").paragraphs().count(), 0);
+        assert_eq!(Reader::new("\n  \n\r\n").paragraphs().count(), 0);
+    }
+}
@@ -1 +1,60 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod render;
 pub mod vec;
+pub mod y2022;
+pub mod y2023;
+
+use error::AocError;
+use std::fmt::Display;
+
+/// A solution to a single day's puzzle. Parsing the input and solving each
+/// part are separate stages, so that each can be called, tested and timed on
+/// its own. Every stage returns an error if the puzzle input is malformed.
+pub trait Solution {
+    /// Puzzle input after parsing
+    type Input;
+
+    /// Parse the puzzle input from the contents of the input file.
+    fn parse(data: &str) -> Result<Self::Input, AocError>;
+
+    /// Compute the answer to part 1.
+    fn part1(input: &Self::Input) -> Result<impl Display, AocError>;
+
+    /// Compute the answer to part 2.
+    fn part2(input: &Self::Input) -> Result<impl Display, AocError>;
+}
+
+/// A solution that can show its work, printing its state after every step.
+pub trait Trace: Solution {
+    /// Solve the given part, calling `f` with the rendered state at the start
+    /// and after every step.
+    fn trace(input: &Self::Input, part: usize, f: impl FnMut(String)) -> Result<(), AocError>;
+}
+
+/// Settings for `Animate::animate`, set from the command line.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AnimateOptions {
+    /// Number of knots in an animated rope, or `None` for the default
+    pub knots: Option<usize>,
+    /// Whether to colour the frames with ANSI escape codes
+    pub color: bool,
+}
+
+/// A solution that can be played as an animation in the terminal.
+pub trait Animate: Solution {
+    /// Returns the rendered frames of the animation, in order.
+    fn animate(input: &Self::Input, options: AnimateOptions) -> impl Iterator<Item = String> + '_;
+}
+
+/// A solution that can generate large random inputs, for benchmarking.
+pub trait Generate: Solution {
+    /// Returns the text of a random input of roughly the given size. What
+    /// the size counts depends on the puzzle, e.g. lines of instructions.
+    fn generate(size: usize) -> String;
+}
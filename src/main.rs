@@ -0,0 +1,463 @@
+use aoc::answers::{self, Answers};
+use aoc::bench::{self, BenchResult};
+use aoc::input::{self, Source};
+use aoc::registry::{find_solvers, Solver};
+use aoc::AnimateOptions;
+use std::io::Write;
+use std::time::Duration;
+
+const USAGE: &str = "Usage:
+    aoc run [YEAR [DAY]] [--part PART] [--input PATH]    run the matching solvers
+    aoc verify [YEAR [DAY]] [--part PART]                check the answers against data/answers.txt
+    aoc bench [YEAR [DAY]] [BENCH OPTIONS]               time the parse, part 1 and part 2 stages
+    aoc animate YEAR DAY [ANIMATE OPTIONS]               animate a solver, if it supports it
+    aoc generate YEAR DAY [--size N]                     print a large random input, for benchmarking
+    aoc list                                             list all registered solvers and what they support
+
+Options:
+    -p, --part PART     only run the given part
+    -i, --input PATH    read the puzzle input from PATH, or stdin if PATH is \"-\".
+                        Can also be set with the AOC_INPUT environment variable.
+    --trace             print the state after every step, for the solvers that support it (2022 day 5)
+
+Bench options:
+    -n, --iterations N      number of times to run each stage (default: 10)
+    --save PATH             save the results as JSON to PATH
+    --baseline PATH         compare against the results saved in PATH
+    --threshold PERCENT     slowdown against the baseline reported as a regression (default: 20)
+
+Animate options (2022 day 9):
+    --knots N           number of knots in the rope (default: 10)
+    --delay MS          delay between frames in milliseconds (default: 50)
+    --output PATH       write the frames to PATH instead of playing them
+
+Generate options (2022 day 5):
+    --size N            number of moves (default: 1000000)";
+
+/// Default number of times each stage is run by `aoc bench`
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// Default slowdown, in percent, reported as a regression by `aoc bench`
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// Default delay, in milliseconds, between frames played by `aoc animate`
+const DEFAULT_DELAY_MS: u64 = 50;
+
+/// Default size of the inputs printed by `aoc generate`
+const DEFAULT_GENERATE_SIZE: usize = 1_000_000;
+
+/// Options parsed from the command line arguments after the subcommand.
+#[derive(Debug, Default)]
+struct Options {
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Option<usize>,
+    input: Option<String>,
+    iterations: Option<usize>,
+    save: Option<String>,
+    baseline: Option<String>,
+    threshold: Option<f64>,
+    knots: Option<usize>,
+    delay: Option<u64>,
+    output: Option<String>,
+    trace: bool,
+    size: Option<usize>,
+}
+
+/// Parse a year, accepting both the full form "2022" and the short form "22".
+fn parse_year(s: &str) -> Result<u32, String> {
+    let year: u32 = s.parse().map_err(|_| format!("Invalid year: {}", s))?;
+    if year < 100 {
+        return Ok(2000 + year);
+    }
+    return Ok(year);
+}
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day: {}", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<usize, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part: {}", s)),
+    }
+}
+
+/// Parse the options from the given arguments.
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut positional: Vec<&str> = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = iter.next().ok_or("Missing value for --part")?;
+                options.part = Some(parse_part(value)?);
+            }
+            "--input" | "-i" => {
+                let value = iter.next().ok_or("Missing value for --input")?;
+                options.input = Some(value.clone());
+            }
+            "--iterations" | "-n" => {
+                let value = iter.next().ok_or("Missing value for --iterations")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => options.iterations = Some(n),
+                    _ => return Err(format!("Invalid number of iterations: {}", value)),
+                }
+            }
+            "--save" => {
+                let value = iter.next().ok_or("Missing value for --save")?;
+                options.save = Some(value.clone());
+            }
+            "--baseline" => {
+                let value = iter.next().ok_or("Missing value for --baseline")?;
+                options.baseline = Some(value.clone());
+            }
+            "--threshold" => {
+                let value = iter.next().ok_or("Missing value for --threshold")?;
+                let threshold = value.parse().map_err(|_| format!("Invalid threshold: {}", value))?;
+                options.threshold = Some(threshold);
+            }
+            "--trace" => options.trace = true,
+            "--size" => {
+                let value = iter.next().ok_or("Missing value for --size")?;
+                let size = value.parse().map_err(|_| format!("Invalid size: {}", value))?;
+                options.size = Some(size);
+            }
+            "--knots" => {
+                let value = iter.next().ok_or("Missing value for --knots")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => options.knots = Some(n),
+                    _ => return Err(format!("Invalid number of knots: {}", value)),
+                }
+            }
+            "--delay" => {
+                let value = iter.next().ok_or("Missing value for --delay")?;
+                let delay = value.parse().map_err(|_| format!("Invalid delay: {}", value))?;
+                options.delay = Some(delay);
+            }
+            "--output" => {
+                let value = iter.next().ok_or("Missing value for --output")?;
+                options.output = Some(value.clone());
+            }
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional[..] {
+        [] => {}
+        [year] => options.year = Some(parse_year(year)?),
+        [year, day] => {
+            options.year = Some(parse_year(year)?);
+            options.day = Some(parse_day(day)?);
+        }
+        _ => return Err(String::from("Too many arguments")),
+    }
+
+    return Ok(options);
+}
+
+/// Returns the matching solvers, each paired with the source of its input.
+fn select_solvers(options: &Options) -> Result<Vec<(&'static Solver, Source)>, String> {
+    let solvers = find_solvers(options.year, options.day);
+    if solvers.is_empty() {
+        return Err(String::from("No solvers found"));
+    }
+
+    // an input override only makes sense for a single puzzle
+    let input_override = input::override_source(options.input.as_deref());
+    if input_override.is_some() && solvers.len() > 1 {
+        return Err(String::from(
+            "An input path can only be given when running a single day",
+        ));
+    }
+
+    let selected = solvers
+        .into_iter()
+        .map(|solver| {
+            let source = input_override
+                .clone()
+                .unwrap_or_else(|| Source::default_for(solver.year, solver.day));
+            (solver, source)
+        })
+        .collect();
+    return Ok(selected);
+}
+
+/// Parse the input of a solver and compute the answers to the given parts.
+/// Returns a list of (part number, answer) pairs.
+fn solve(solver: &Solver, source: &Source, part: Option<usize>) -> Result<Vec<(usize, String)>, String> {
+    let data = source
+        .read()
+        .map_err(|e| format!("Failed to read input {}: {}", source, e))?;
+    let input = (solver.parse)(&data).map_err(|e| e.in_file(source).to_string())?;
+
+    let mut answers: Vec<(usize, String)> = Vec::new();
+    for (i, run_part) in solver.parts.iter().enumerate() {
+        let part_number = i + 1;
+        if part.is_some_and(|p| p != part_number) {
+            continue;
+        }
+        let answer = run_part(input.as_ref()).map_err(|e| e.in_file(source).to_string())?;
+        answers.push((part_number, answer));
+    }
+    return Ok(answers);
+}
+
+/// Print the state of a solver after every step of the given parts.
+fn trace(solver: &Solver, source: &Source, part: Option<usize>) -> Result<(), String> {
+    let Some(trace) = solver.trace else {
+        return Err(format!("No trace for {} day {}", solver.year, solver.day));
+    };
+    let data = source
+        .read()
+        .map_err(|e| format!("Failed to read input {}: {}", source, e))?;
+    let input = (solver.parse)(&data).map_err(|e| e.in_file(source).to_string())?;
+
+    for part_number in 1..=2 {
+        if part.is_some_and(|p| p != part_number) {
+            continue;
+        }
+        println!("{} day {} part {} trace:", solver.year, solver.day, part_number);
+        trace(input.as_ref(), part_number, &mut |step| println!("{}", step))
+            .map_err(|e| e.in_file(source).to_string())?;
+    }
+    return Ok(());
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let solvers = select_solvers(&options)?;
+
+    // when running several days, only trace the ones that support it
+    if options.trace && options.day.is_some() && solvers[0].0.trace.is_none() {
+        return Err(format!("No trace for {} day {}", solvers[0].0.year, solvers[0].0.day));
+    }
+
+    for (solver, source) in solvers {
+        if options.trace && solver.trace.is_some() {
+            trace(solver, &source, options.part)?;
+        }
+        for (part, answer) in solve(solver, &source, options.part)? {
+            println!("{} day {} part {}: {}", solver.year, solver.day, part, answer);
+        }
+    }
+    return Ok(());
+}
+
+/// Run the solvers and compare their answers against the answers file.
+fn verify(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    // the answers file holds the answers for the default inputs only
+    if input::override_source(options.input.as_deref()).is_some() {
+        return Err(String::from(
+            "verify always uses the default inputs; --input and AOC_INPUT are not supported",
+        ));
+    }
+    let answers = Answers::load(&answers::default_path())?;
+
+    let mut num_passed = 0;
+    let mut num_failed = 0;
+    let mut num_missing = 0;
+
+    for (solver, source) in select_solvers(&options)? {
+        let solved = match solve(solver, &source, options.part) {
+            Ok(solved) => solved,
+            Err(message) => {
+                // a solver that fails fails every part it was asked for
+                for part in (1..=2).filter(|&p| options.part.is_none_or(|selected| selected == p)) {
+                    num_failed += 1;
+                    println!("{} day {} part {}: FAIL ({})", solver.year, solver.day, part, message);
+                }
+                continue;
+            }
+        };
+        for (part, answer) in solved {
+            let status = match answers.get(solver.year, solver.day, part) {
+                Some(expected) if expected == answer => {
+                    num_passed += 1;
+                    String::from("pass")
+                }
+                Some(expected) => {
+                    num_failed += 1;
+                    format!("FAIL (expected {}, got {})", expected, answer)
+                }
+                None => {
+                    num_missing += 1;
+                    format!("missing answer (got {})", answer)
+                }
+            };
+            println!("{} day {} part {}: {}", solver.year, solver.day, part, status);
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", num_passed, num_failed, num_missing);
+    if num_failed > 0 {
+        return Err(format!("{} answers failed", num_failed));
+    }
+    return Ok(());
+}
+
+/// Time each stage of the solvers, optionally saving the results and
+/// comparing them against a saved baseline.
+fn bench(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let iterations = options.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let threshold = options.threshold.unwrap_or(DEFAULT_THRESHOLD) / 100.0;
+
+    let baseline: Vec<BenchResult> = match &options.baseline {
+        Some(path) => {
+            let json = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+            bench::from_json(&json)?
+        }
+        None => Vec::new(),
+    };
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    let mut num_regressions = 0;
+
+    for (solver, source) in select_solvers(&options)? {
+        let data = source
+            .read()
+            .map_err(|e| format!("Failed to read input {}: {}", source, e))?;
+
+        let solver_results =
+            bench::bench_solver(solver, &data, options.part, iterations).map_err(|e| e.in_file(&source).to_string())?;
+        for result in solver_results {
+            let stats = &result.stats;
+            print!(
+                "{} day {} {:<5}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+                result.year, result.day, result.stage, stats.min, stats.median, stats.max
+            );
+            match bench::find_regression(&result, &baseline, threshold) {
+                Some(slowdown) => {
+                    num_regressions += 1;
+                    println!("  REGRESSION ({:+.0}%)", slowdown * 100.0);
+                }
+                None => println!(),
+            }
+            results.push(result);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        std::fs::write(path, bench::to_json(&results)).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+
+    if num_regressions > 0 {
+        return Err(format!("{} stages regressed against the baseline", num_regressions));
+    }
+    return Ok(());
+}
+
+/// Play animation frames in the terminal, or write them to a file if an
+/// output path is given.
+fn play_frames(frames: impl Iterator<Item = String>, options: &Options) -> Result<(), String> {
+    match &options.output {
+        Some(path) => {
+            let text: String = frames
+                .enumerate()
+                .map(|(step, frame)| format!("Step {}\n{}\n", step, frame))
+                .collect();
+            std::fs::write(path, text).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        }
+        None => {
+            let delay = Duration::from_millis(options.delay.unwrap_or(DEFAULT_DELAY_MS));
+            for (step, frame) in frames.enumerate() {
+                // clear the screen and move the cursor to the top-left corner
+                print!("\x1b[2J\x1b[HStep {}\n{}", step, frame);
+                std::io::stdout().flush().map_err(|e| e.to_string())?;
+                std::thread::sleep(delay);
+            }
+        }
+    }
+    return Ok(());
+}
+
+/// Play an animation of a solver in the terminal, or write its frames to a file.
+fn animate(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    if options.day.is_none() {
+        return Err(String::from("A year and day must be given to animate"));
+    }
+    let (solver, source) = select_solvers(&options)?.remove(0);
+    let Some(animate) = solver.animate else {
+        return Err(format!("No animation for {} day {}", solver.year, solver.day));
+    };
+
+    let data = source
+        .read()
+        .map_err(|e| format!("Failed to read input {}: {}", source, e))?;
+    let input = (solver.parse)(&data).map_err(|e| e.in_file(&source).to_string())?;
+
+    let animate_options = AnimateOptions {
+        knots: options.knots,
+        // colours are only shown when playing in the terminal
+        color: options.output.is_none(),
+    };
+    return play_frames(animate(input.as_ref(), animate_options), &options);
+}
+
+/// Print a large random input for a solver, e.g. to benchmark it with
+/// `aoc generate 2022 5 | aoc bench 2022 5 --input -`.
+fn generate(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    if options.day.is_none() {
+        return Err(String::from("A year and day must be given to generate an input"));
+    }
+    let (solver, _) = select_solvers(&options)?.remove(0);
+    let Some(generate) = solver.generate else {
+        return Err(format!("No input generator for {} day {}", solver.year, solver.day));
+    };
+
+    print!("{}", generate(options.size.unwrap_or(DEFAULT_GENERATE_SIZE)));
+    return Ok(());
+}
+
+/// Print every registered solver, with the extra subcommands it supports.
+fn list() {
+    for solver in find_solvers(None, None) {
+        let hooks: Vec<&str> = [
+            ("trace", solver.trace.is_some()),
+            ("animate", solver.animate.is_some()),
+            ("generate", solver.generate.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, supported)| supported.then_some(name))
+        .collect();
+        if hooks.is_empty() {
+            println!("{} day {}", solver.year, solver.day);
+        } else {
+            println!("{} day {} ({})", solver.year, solver.day, hooks.join(", "));
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("animate") => animate(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        _ => Err(String::from(USAGE)),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
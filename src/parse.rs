@@ -0,0 +1,38 @@
+use crate::error::AocError;
+use crate::input::{Paragraph, Reader};
+use std::str::FromStr;
+
+/// Parse a number, e.g. "123".
+pub fn parse_num<T: FromStr>(s: &str) -> Result<T, AocError> {
+    s.parse().map_err(|_| AocError::invalid("Invalid number", s))
+}
+
+/// Split a string at the first occurrence of `delimiter`, e.g. "1-2" at "-".
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), AocError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| AocError::invalid(format!("Expected {:?} in", delimiter), s))
+}
+
+/// Parse each normalized line of the input with `f`, attaching the line
+/// number to any error.
+pub fn parse_lines<T>(data: &str, mut f: impl FnMut(&str) -> Result<T, AocError>) -> Result<Vec<T>, AocError> {
+    Reader::new(data)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1, line)))
+        .collect()
+}
+
+/// Parse each line of a paragraph with `f`, attaching the line number in the
+/// input to any error.
+pub fn parse_paragraph_lines<T>(
+    paragraph: &Paragraph,
+    mut f: impl FnMut(&str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    paragraph
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(paragraph.line + i, line)))
+        .collect()
+}
@@ -0,0 +1,147 @@
+use crate::error::AocError;
+use crate::{y2022, y2023, Animate, AnimateOptions, Generate, Solution, Trace};
+use std::any::Any;
+
+/// Type-erased `Solution::parse`
+pub type ParseFn = fn(&str) -> Result<Box<dyn Any>, AocError>;
+
+/// Type-erased `Solution::part1` or `Solution::part2`
+pub type PartFn = fn(&dyn Any) -> Result<String, AocError>;
+
+/// Type-erased `Trace::trace`
+pub type TraceFn = fn(&dyn Any, usize, &mut dyn FnMut(String)) -> Result<(), AocError>;
+
+/// Type-erased `Animate::animate`
+pub type AnimateFn = fn(&dyn Any, AnimateOptions) -> Box<dyn Iterator<Item = String> + '_>;
+
+/// `Generate::generate`
+pub type GenerateFn = fn(usize) -> String;
+
+/// A puzzle solver for a single day, registered so it can be run from the
+/// `aoc` binary. The input type of the underlying `Solution` is erased, so
+/// that solvers for different days can be stored together.
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    /// Parses the puzzle input
+    pub parse: ParseFn,
+    /// Functions computing the answers to part 1 and part 2, in that order,
+    /// from the output of `parse`
+    pub parts: [PartFn; 2],
+    /// Optional hooks for the solutions that support them, taking the output
+    /// of `parse` where they need the puzzle input
+    pub trace: Option<TraceFn>,
+    pub animate: Option<AnimateFn>,
+    pub generate: Option<GenerateFn>,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(year: u32, day: u32) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year,
+            day,
+            parse: parse_erased::<S>,
+            parts: [part1_erased::<S>, part2_erased::<S>],
+            trace: None,
+            animate: None,
+            generate: None,
+        }
+    }
+
+    /// Register the `Trace` implementation of the solution.
+    pub const fn with_trace<S: Trace>(mut self) -> Self
+    where
+        S::Input: 'static,
+    {
+        self.trace = Some(trace_erased::<S>);
+        return self;
+    }
+
+    /// Register the `Animate` implementation of the solution.
+    pub const fn with_animate<S: Animate>(mut self) -> Self
+    where
+        S::Input: 'static,
+    {
+        self.animate = Some(animate_erased::<S>);
+        return self;
+    }
+
+    /// Register the `Generate` implementation of the solution.
+    pub const fn with_generate<S: Generate>(mut self) -> Self {
+        self.generate = Some(S::generate);
+        return self;
+    }
+}
+
+fn parse_erased<S: Solution>(data: &str) -> Result<Box<dyn Any>, AocError>
+where
+    S::Input: 'static,
+{
+    let input = S::parse(data)?;
+    Ok(Box::new(input))
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any) -> Result<String, AocError>
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref::<S::Input>().expect("Input type mismatch");
+    S::part1(input).map(|answer| answer.to_string())
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any) -> Result<String, AocError>
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref::<S::Input>().expect("Input type mismatch");
+    S::part2(input).map(|answer| answer.to_string())
+}
+
+fn trace_erased<S: Trace>(input: &dyn Any, part: usize, f: &mut dyn FnMut(String)) -> Result<(), AocError>
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref::<S::Input>().expect("Input type mismatch");
+    S::trace(input, part, f)
+}
+
+fn animate_erased<S: Animate>(input: &dyn Any, options: AnimateOptions) -> Box<dyn Iterator<Item = String> + '_>
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref::<S::Input>().expect("Input type mismatch");
+    Box::new(S::animate(input, options))
+}
+
+/// All registered solvers, ordered by year and day
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<y2022::day1::Day1>(2022, 1),
+    Solver::new::<y2022::day2::Day2>(2022, 2),
+    Solver::new::<y2022::day3::Day3>(2022, 3),
+    Solver::new::<y2022::day4::Day4>(2022, 4),
+    Solver::new::<y2022::day5::Day5>(2022, 5)
+        .with_trace::<y2022::day5::Day5>()
+        .with_generate::<y2022::day5::Day5>(),
+    Solver::new::<y2022::day6::Day6>(2022, 6),
+    Solver::new::<y2022::day7::Day7>(2022, 7),
+    Solver::new::<y2022::day8::Day8>(2022, 8),
+    Solver::new::<y2022::day9::Day9>(2022, 9).with_animate::<y2022::day9::Day9>(),
+    Solver::new::<y2023::day1::Day1>(2023, 1),
+    Solver::new::<y2023::day2::Day2>(2023, 2),
+    Solver::new::<y2023::day3::Day3>(2023, 3),
+    Solver::new::<y2023::day4::Day4>(2023, 4),
+];
+
+/// Returns all solvers matching the given year and day. A `None` matches
+/// every year or day.
+pub fn find_solvers(year: Option<u32>, day: Option<u32>) -> Vec<&'static Solver> {
+    let solvers: Vec<&Solver> = SOLVERS
+        .iter()
+        .filter(|s| year.is_none_or(|y| s.year == y))
+        .filter(|s| day.is_none_or(|d| s.day == d))
+        .collect();
+    return solvers;
+}
@@ -0,0 +1,177 @@
+use crate::grid::{Grid, SparseGrid};
+use crate::vec::{Rect, Vec2d};
+use std::collections::HashMap;
+
+/// Foreground colour of a rendered cell.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// Returns the ANSI escape code setting this foreground colour.
+    fn ansi_code(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::White => "\x1b[37m",
+            Color::Gray => "\x1b[90m",
+        }
+    }
+}
+
+/// ANSI escape code resetting the colour
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// A character drawn at one position, with an optional colour.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub fn new(glyph: char, color: Color) -> Self {
+        Self {
+            glyph,
+            color: Some(color),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(glyph: char) -> Self {
+        Self { glyph, color: None }
+    }
+}
+
+/// Which way the y-axis points on screen.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum YAxis {
+    /// +y is down, as for `Grid` rows. The row with the smallest y is printed first.
+    #[default]
+    Down,
+    /// +y is up, as on a plot. The row with the largest y is printed first.
+    Up,
+}
+
+/// A layer returns the cell to draw at a position, or `None` to show the
+/// layers below it.
+type Layer<'a> = Box<dyn Fn(Vec2d) -> Option<Cell> + 'a>;
+
+/// Renders a region of the plane as text, one line per row. The region is
+/// drawn from a stack of layers, e.g. a grid with highlighted points on top.
+/// Positions not covered by any layer are drawn as spaces.
+pub struct Renderer<'a> {
+    bounds: Rect,
+    y_axis: YAxis,
+    color: bool,
+    /// Layers from bottom to top
+    layers: Vec<Layer<'a>>,
+}
+
+impl<'a> Renderer<'a> {
+    /// Create a renderer for the given region, with no layers.
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            y_axis: YAxis::Down,
+            color: false,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Create a renderer for a dense grid, drawing each cell with `glyph`.
+    pub fn grid<T, C: Into<Cell>>(grid: &'a Grid<T>, glyph: impl Fn(&T) -> C + 'a) -> Self {
+        Self::new(grid.bounds()).overlay(move |pos| grid.get(pos).map(|value| glyph(value).into()))
+    }
+
+    /// Create a renderer for the bounding rectangle of a sparse grid, drawing
+    /// each cell, set or not, with `glyph`.
+    pub fn sparse<T, C: Into<Cell>>(grid: &'a SparseGrid<T>, glyph: impl Fn(&T) -> C + 'a) -> Self {
+        Self::new(grid.bounds()).overlay(move |pos| Some(glyph(grid.get(pos)).into()))
+    }
+
+    /// Set the region to draw.
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = bounds;
+        return self;
+    }
+
+    /// Set which way the y-axis points. Defaults to `YAxis::Down`.
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        return self;
+    }
+
+    /// Set whether to colour cells with ANSI escape codes. Defaults to false.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        return self;
+    }
+
+    /// Add a layer on top of the existing ones.
+    pub fn overlay(mut self, layer: impl Fn(Vec2d) -> Option<Cell> + 'a) -> Self {
+        self.layers.push(Box::new(layer));
+        return self;
+    }
+
+    /// Add a layer drawing the given points on top of the existing ones. If a
+    /// point is repeated, the first cell for it is drawn.
+    pub fn overlay_points(self, points: impl IntoIterator<Item = (Vec2d, Cell)>) -> Self {
+        let mut cells: HashMap<Vec2d, Cell> = HashMap::new();
+        for (pos, cell) in points {
+            cells.entry(pos).or_insert(cell);
+        }
+        self.overlay(move |pos| cells.get(&pos).copied())
+    }
+
+    /// Returns the cell drawn at the position by the topmost layer covering it.
+    fn cell_at(&self, pos: Vec2d) -> Cell {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer(pos))
+            .unwrap_or(Cell::from(' '))
+    }
+
+    /// Render the region as text, with each row ending in a newline.
+    pub fn render(&self) -> String {
+        let ys = self.bounds.min.y..self.bounds.max.y;
+        let ys: Box<dyn Iterator<Item = i32>> = match self.y_axis {
+            YAxis::Down => Box::new(ys),
+            YAxis::Up => Box::new(ys.rev()),
+        };
+
+        let mut text = String::new();
+        for y in ys {
+            let mut current_color = None;
+            for x in self.bounds.min.x..self.bounds.max.x {
+                let cell = self.cell_at(Vec2d { x, y });
+                let color = if self.color { cell.color } else { None };
+                // only emit escape codes when the colour changes
+                if color != current_color {
+                    text.push_str(color.map_or(ANSI_RESET, Color::ansi_code));
+                    current_color = color;
+                }
+                text.push(cell.glyph);
+            }
+            if current_color.is_some() {
+                text.push_str(ANSI_RESET);
+            }
+            text.push('\n');
+        }
+        return text;
+    }
+}
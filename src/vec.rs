@@ -1,19 +1,24 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::error::AocError;
+use crate::parse::{parse_num, split_once};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+/// A 2D vector with named fields. Vectors are ordered by `x`, then by `y`.
+/// The operators and metrics are those of `VecN<T, 2>`, which `Vec2` converts
+/// to and from.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Vec2<T> {
     pub x: T,
     pub y: T,
 }
 
-impl<T: Add<Output = T>> Add for Vec2<T> {
+impl<T: Add<Output = T> + Copy> Add for Vec2<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+        (VecN::from(self) + VecN::from(rhs)).into()
     }
 }
 
@@ -21,10 +26,7 @@ impl<T: Div<Output = T> + Copy> Div<T> for Vec2<T> {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
+        (VecN::from(self) / rhs).into()
     }
 }
 
@@ -32,30 +34,666 @@ impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
+        (VecN::from(self) * rhs).into()
+    }
+}
+
+impl<T: Neg<Output = T> + Copy> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        (-VecN::from(self)).into()
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (VecN::from(self) - VecN::from(rhs)).into()
+    }
+}
+
+impl<T: AddAssign + Copy> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        let mut v = VecN::from(*self);
+        v += VecN::from(rhs);
+        *self = v.into();
+    }
+}
+
+impl<T: DivAssign + Copy> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        let mut v = VecN::from(*self);
+        v /= rhs;
+        *self = v.into();
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        let mut v = VecN::from(*self);
+        v *= rhs;
+        *self = v.into();
+    }
+}
+
+impl<T: SubAssign + Copy> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        let mut v = VecN::from(*self);
+        v -= VecN::from(rhs);
+        *self = v.into();
+    }
+}
+
+impl<T: Add<Output = T> + Copy + Default> Sum for Vec2<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, v| acc + v)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+/// Formats as "(x, y)".
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Parses the form "x,y", e.g. "3,-4". Whitespace around each number is allowed.
+impl<T: FromStr> FromStr for Vec2<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = split_once(s, ",")?;
+        return Ok(Self {
+            x: parse_num(x.trim())?,
+            y: parse_num(y.trim())?,
+        });
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Copy + Default> Vec2<T> {
+    pub fn dot(self, rhs: Self) -> T {
+        VecN::from(self).dot(VecN::from(rhs))
+    }
+
+    /// Squared Euclidean distance to another point.
+    pub fn euclidean_sq(self, rhs: Self) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        VecN::from(self).euclidean_sq(VecN::from(rhs))
+    }
+}
+
+impl<T: Sub<Output = T> + Mul<Output = T> + Copy> Vec2<T> {
+    /// The z-component of the 3D cross product, which is positive if `rhs` is
+    /// anticlockwise from `self` when the y-axis points up.
+    pub fn cross(self, rhs: Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl<T: Ord + Copy> Vec2<T> {
+    /// Component-wise minimum.
+    pub fn min(self, rhs: Self) -> Self {
+        VecN::from(self).min(VecN::from(rhs)).into()
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, rhs: Self) -> Self {
+        VecN::from(self).max(VecN::from(rhs)).into()
+    }
+}
+
+/// Methods that need the absolute value or sign of a component.
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Vec2<$t> {
+                /// Component-wise absolute value.
+                pub fn abs(self) -> Self {
+                    VecN::from(self).abs().into()
+                }
+
+                /// Component-wise sign, each one of -1, 0 or 1.
+                pub fn signum(self) -> Self {
+                    VecN::from(self).signum().into()
+                }
+
+                /// Manhattan (taxicab) distance to another point.
+                pub fn manhattan(self, rhs: Self) -> $t {
+                    VecN::from(self).manhattan(VecN::from(rhs))
+                }
+
+                /// Chebyshev (chessboard) distance to another point, i.e. the
+                /// number of king moves between them.
+                pub fn chebyshev(self, rhs: Self) -> $t {
+                    VecN::from(self).chebyshev(VecN::from(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+pub type Vec2d = Vec2<i32>;
+pub type Vec3d = VecN<i32, 3>;
+pub type Vec4d = VecN<i32, 4>;
+
+impl Vec2d {
+    /// Returns the position as (column, row) indices into a grid of the given
+    /// size, or `None` if it is outside the grid. Negative coordinates are
+    /// never wrapped into large indices.
+    pub fn to_index(self, width: usize, height: usize) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.x).ok()?;
+        let y = usize::try_from(self.y).ok()?;
+        if x >= width || y >= height {
+            return None;
+        }
+        return Some((x, y));
+    }
+
+    /// Returns true if the point lies inside the rectangle.
+    pub fn in_bounds(self, rect: &Rect) -> bool {
+        rect.contains(self)
+    }
+}
+
+/// An axis-aligned rectangle of integer points, including `min` but excluding
+/// `max`, like a range. The rectangle is empty if `max` is not greater than
+/// `min` in both coordinates.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Rect {
+    pub min: Vec2d,
+    pub max: Vec2d,
+}
+
+impl Rect {
+    pub fn new(min: Vec2d, max: Vec2d) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the rectangle covering a grid of the given size, with its
+    /// top-left corner at the origin.
+    pub fn from_size(width: usize, height: usize) -> Self {
         Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
+            min: Vec2d::default(),
+            max: Vec2d {
+                x: width as i32,
+                y: height as i32,
+            },
+        }
+    }
+
+    /// Returns the smallest rectangle containing all the points, or an empty
+    /// rectangle if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Vec2d>) -> Self {
+        let mut points = points.into_iter();
+        let Some(first) = points.next() else {
+            return Self::default();
+        };
+        let (min, max) = points.fold((first, first), |(min, max), pos| (min.min(pos), max.max(pos)));
+        return Self {
+            min,
+            max: max + Vec2d { x: 1, y: 1 },
+        };
+    }
+
+    pub fn width(&self) -> i32 {
+        (self.max.x - self.min.x).max(0)
+    }
+
+    pub fn height(&self) -> i32 {
+        (self.max.y - self.min.y).max(0)
+    }
+
+    /// Returns the number of points in the rectangle.
+    pub fn area(&self) -> i64 {
+        self.width() as i64 * self.height() as i64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    /// Returns true if the point lies inside the rectangle.
+    pub fn contains(&self, pos: Vec2d) -> bool {
+        self.min.x <= pos.x && pos.x < self.max.x && self.min.y <= pos.y && pos.y < self.max.y
+    }
+
+    /// Returns the points common to both rectangles, which may be empty.
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        return Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        };
+    }
+
+    /// Returns the smallest rectangle containing this one and the point.
+    pub fn include(&self, pos: Vec2d) -> Rect {
+        self.union(&Self::from_points([pos]))
+    }
+
+    /// Grow the rectangle by `amount` on every side. A negative amount shrinks it.
+    pub fn expand(&self, amount: i32) -> Rect {
+        let margin = Vec2d { x: amount, y: amount };
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Returns every point in the rectangle, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Vec2d> {
+        let (min, max) = (self.min, self.max);
+        (min.y..max.y).flat_map(move |y| (min.x..max.x).map(move |x| Vec2d { x, y }))
+    }
+}
+
+/// One of the eight compass directions. The four cardinal directions are
+/// listed in `Direction::CARDINAL` and all eight in `Direction::ALL`.
+///
+/// As with `Grid`, the y-axis points down, so that north is `(0, -1)`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north
+    pub const CARDINAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// All eight directions, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Rotate clockwise by the given number of eighths of a turn. Negative
+    /// values rotate anticlockwise.
+    pub fn rotate(self, eighths: i32) -> Self {
+        let i = Self::ALL.iter().position(|&d| d == self).unwrap() as i32;
+        return Self::ALL[(i + eighths).rem_euclid(8) as usize];
+    }
+
+    /// Rotate 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    /// Rotate 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns true for north, east, south and west.
+    pub fn is_cardinal(self) -> bool {
+        Self::CARDINAL.contains(&self)
+    }
+
+    /// Returns the unit step in this direction.
+    pub fn to_vec(self) -> Vec2d {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        return Vec2d { x, y };
+    }
+
+    /// Returns the direction of a unit step, or `None` if the vector is not
+    /// one, e.g. `(0, 0)` or `(2, 0)`.
+    pub fn from_vec(v: Vec2d) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.to_vec() == v)
+    }
+
+    /// Parse a direction from "U", "R", "D" or "L".
+    pub fn parse_letter(s: &str) -> Result<Self, AocError> {
+        match s {
+            "U" => Ok(Direction::North),
+            "R" => Ok(Direction::East),
+            "D" => Ok(Direction::South),
+            "L" => Ok(Direction::West),
+            _ => Err(AocError::invalid("Invalid direction", s)),
+        }
+    }
+
+    /// Parse a direction from a compass point, e.g. "N" or "SW".
+    pub fn parse_compass(s: &str) -> Result<Self, AocError> {
+        match s {
+            "N" => Ok(Direction::North),
+            "NE" => Ok(Direction::NorthEast),
+            "E" => Ok(Direction::East),
+            "SE" => Ok(Direction::SouthEast),
+            "S" => Ok(Direction::South),
+            "SW" => Ok(Direction::SouthWest),
+            "W" => Ok(Direction::West),
+            "NW" => Ok(Direction::NorthWest),
+            _ => Err(AocError::invalid("Invalid direction", s)),
+        }
+    }
+
+    /// Parse a direction from an arrow, one of '^', '>', 'v' or '<'.
+    pub fn parse_arrow(c: char) -> Result<Self, AocError> {
+        match c {
+            '^' => Ok(Direction::North),
+            '>' => Ok(Direction::East),
+            'v' => Ok(Direction::South),
+            '<' => Ok(Direction::West),
+            _ => Err(AocError::invalid("Invalid direction", &c.to_string())),
         }
     }
 }
 
-impl<T: Neg<Output = T>> Neg for Vec2<T> {
+impl From<Direction> for Vec2d {
+    fn from(direction: Direction) -> Self {
+        direction.to_vec()
+    }
+}
+
+impl TryFrom<Vec2d> for Direction {
+    type Error = AocError;
+
+    fn try_from(v: Vec2d) -> Result<Self, Self::Error> {
+        Self::from_vec(v).ok_or_else(|| AocError::new(format!("{} is not a unit step", v)))
+    }
+}
+
+/// Parses any of the forms accepted by `parse_letter`, `parse_compass` and
+/// `parse_arrow`.
+impl FromStr for Direction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if let Ok(direction) = Self::parse_arrow(c) {
+                return Ok(direction);
+            }
+        }
+        Self::parse_letter(s).or_else(|_| Self::parse_compass(s))
+    }
+}
+
+/// An N-dimensional vector, for puzzles in three or more dimensions. Vectors
+/// are ordered lexicographically by component. `Vec2` is kept as a separate
+/// type rather than an alias for `VecN<T, 2>`, so that its fields have names,
+/// but its operators and metrics are implemented by converting to `VecN`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> VecN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<T: Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.0[i]
+    }
+}
+
+impl<T: Add<Output = T> + Copy, const N: usize> Add for VecN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<T: Div<Output = T> + Copy, const N: usize> Div<T> for VecN<T, N> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] / rhs))
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i] * rhs))
+    }
+}
+
+impl<T: Neg<Output = T> + Copy, const N: usize> Neg for VecN<T, N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self { x: -self.x, y: -self.y }
+        Self(std::array::from_fn(|i| -self.0[i]))
     }
 }
 
-impl<T: Sub<Output = T>> Sub for Vec2<T> {
+impl<T: Sub<Output = T> + Copy, const N: usize> Sub for VecN<T, N> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
+        Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<T: AddAssign, const N: usize> AddAssign for VecN<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a += b;
         }
     }
 }
 
-pub type Vec2d = Vec2<i32>;
+impl<T: DivAssign + Copy, const N: usize> DivAssign<T> for VecN<T, N> {
+    fn div_assign(&mut self, rhs: T) {
+        for a in self.0.iter_mut() {
+            *a /= rhs;
+        }
+    }
+}
+
+impl<T: MulAssign + Copy, const N: usize> MulAssign<T> for VecN<T, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        for a in self.0.iter_mut() {
+            *a *= rhs;
+        }
+    }
+}
+
+impl<T: SubAssign, const N: usize> SubAssign for VecN<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T: Add<Output = T> + Copy + Default, const N: usize> Sum for VecN<T, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, v| acc + v)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<T, const N: usize> From<VecN<T, N>> for [T; N] {
+    fn from(v: VecN<T, N>) -> Self {
+        v.0
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self {
+        Self([v.x, v.y])
+    }
+}
+
+impl<T> From<VecN<T, 2>> for Vec2<T> {
+    fn from(VecN([x, y]): VecN<T, 2>) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Formats as "(x, y, z, ...)".
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, component) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", component)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Parses the form "x,y,z,...", e.g. "1,-2,3", with exactly N components.
+/// Whitespace around each number is allowed.
+impl<T: FromStr + Default, const N: usize> FromStr for VecN<T, N> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(',').collect();
+        if parts.len() != N {
+            return Err(AocError::invalid(format!("Expected {} components in", N), s));
+        }
+        let mut v = Self::default();
+        for (component, part) in v.0.iter_mut().zip(parts) {
+            *component = parse_num(part.trim())?;
+        }
+        return Ok(v);
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Copy + Default, const N: usize> VecN<T, N> {
+    pub fn dot(self, rhs: Self) -> T {
+        self.0.iter().zip(rhs.0).fold(T::default(), |acc, (&a, b)| acc + a * b)
+    }
+
+    /// Squared Euclidean distance to another point.
+    pub fn euclidean_sq(self, rhs: Self) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        let d = self - rhs;
+        d.dot(d)
+    }
+}
+
+impl<T: Sub<Output = T> + Mul<Output = T> + Copy> VecN<T, 3> {
+    pub fn cross(self, rhs: Self) -> Self {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = rhs.0;
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<T: Ord + Copy, const N: usize> VecN<T, N> {
+    /// Component-wise minimum.
+    pub fn min(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i].min(rhs.0[i])))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i].max(rhs.0[i])))
+    }
+}
+
+/// Methods of `VecN` that need the absolute value or sign of a component.
+macro_rules! impl_signed_n {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> VecN<$t, N> {
+                /// Component-wise absolute value.
+                pub fn abs(self) -> Self {
+                    Self(self.0.map(|a| a.abs()))
+                }
+
+                /// Component-wise sign, each one of -1, 0 or 1.
+                pub fn signum(self) -> Self {
+                    Self(self.0.map(|a| a.signum()))
+                }
+
+                /// Manhattan (taxicab) distance to another point.
+                pub fn manhattan(self, rhs: Self) -> $t {
+                    (self - rhs).abs().0.iter().sum()
+                }
+
+                /// Chebyshev (chessboard) distance to another point.
+                pub fn chebyshev(self, rhs: Self) -> $t {
+                    (self - rhs).abs().0.into_iter().max().unwrap_or(0)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_n!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,69 @@
+use crate::error::AocError;
+use crate::input::Reader;
+use crate::parse::{parse_num, parse_paragraph_lines};
+use crate::Solution;
+use std::fmt::Display;
+
+/// Returns the calories held by each elf
+fn get_calories(data: &str) -> Result<Vec<i32>, AocError> {
+    let mut calories: Vec<i32> = Vec::new(); // calories for each elf
+
+    // each paragraph lists the calories of the items carried by one elf
+    for paragraph in Reader::new(data).paragraphs() {
+        let items: Vec<i32> = parse_paragraph_lines(&paragraph, parse_num)?;
+        calories.push(items.iter().sum());
+    }
+
+    return Ok(calories);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    /// Returns the calories held by each elf, sorted in ascending order
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let mut calories = get_calories(data)?;
+
+        // sort the calories; the last element is the max
+        // NOTE: this is asymptotically too slow, but easy to implement and fast enough for this problem
+        calories.sort();
+
+        return Ok(calories);
+    }
+
+    fn part1(calories: &Self::Input) -> Result<impl Display, AocError> {
+        let max = calories
+            .last()
+            .ok_or_else(|| AocError::new("Expected at least one elf"))?;
+        return Ok(*max);
+    }
+
+    fn part2(calories: &Self::Input) -> Result<impl Display, AocError> {
+        if calories.len() < 3 {
+            return Err(AocError::new("Expected at least three elves"));
+        }
+        let top_three = &calories[(calories.len() - 3)..];
+        let top_three_sum: i32 = top_three.iter().sum();
+        return Ok(top_three_sum);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_group_is_counted() {
+        // the last group sums to 0, and is followed by repeated blank lines
+        assert_eq!(get_calories("1\n\n\n0\n\n").unwrap(), [1, 0]);
+        assert_eq!(get_calories("1\r\n2\r\n\r\n3\r\n").unwrap(), [3, 3]);
+    }
+
+    #[test]
+    fn errors_report_the_line() {
+        let e = get_calories("1\n\n\n2\nx\n").unwrap_err();
+        assert_eq!(e.line, Some(5));
+    }
+}
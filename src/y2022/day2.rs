@@ -1,37 +1,39 @@
-/// File containing puzzle data
-const DATA: &'static str = "data/22day2.txt";
+use crate::error::AocError;
+use crate::parse::parse_lines;
+use crate::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum Shape {
+pub enum Shape {
     Rock,
     Paper,
     Scissors,
 }
 
 #[derive(Debug)]
-enum EncodedShape {
+pub enum EncodedShape {
     X,
     Y,
     Z,
 }
 
 /// Parse shape from character A, B, or C.
-fn parse_shape(s: char) -> Shape {
+fn parse_shape(s: char) -> Result<Shape, AocError> {
     match s {
-        'A' => Shape::Rock,
-        'B' => Shape::Paper,
-        'C' => Shape::Scissors,
-        _ => panic!("Unexpected shape: {}", s),
+        'A' => Ok(Shape::Rock),
+        'B' => Ok(Shape::Paper),
+        'C' => Ok(Shape::Scissors),
+        _ => Err(AocError::invalid("Unexpected shape", &s.to_string())),
     }
 }
 
 /// Parse encoded shape from character X, Y, or Z.
-fn parse_encoded_shape(s: char) -> EncodedShape {
+fn parse_encoded_shape(s: char) -> Result<EncodedShape, AocError> {
     match s {
-        'X' => EncodedShape::X,
-        'Y' => EncodedShape::Y,
-        'Z' => EncodedShape::Z,
-        _ => panic!("Unexpected encoded shape: {}", s),
+        'X' => Ok(EncodedShape::X),
+        'Y' => Ok(EncodedShape::Y),
+        'Z' => Ok(EncodedShape::Z),
+        _ => Err(AocError::invalid("Unexpected encoded shape", &s.to_string())),
     }
 }
 
@@ -72,20 +74,20 @@ fn score_round(opponent_shape: &Shape, player_shape: &Shape) -> i32 {
 }
 
 /// Get the opponent shape and player encoded shape for each round.
-fn get_rounds() -> Vec<(Shape, EncodedShape)> {
-    let mut rounds: Vec<(Shape, EncodedShape)> = Vec::new();
-
-    // iterate over lines of the input file
-    let data = std::fs::read_to_string(DATA).unwrap();
-
+fn get_rounds(data: &str) -> Result<Vec<(Shape, EncodedShape)>, AocError> {
     // each line has the format "<A|B|C> <X|Y|Z>"
-    for line in data.split("\n") {
-        let shape = parse_shape(line.chars().nth(0).unwrap());
-        let encoded_shape = parse_encoded_shape(line.chars().nth(2).unwrap());
-        rounds.push((shape, encoded_shape));
-    }
-
-    return rounds;
+    parse_lines(data, |line| {
+        let chars: Vec<char> = line.chars().collect();
+        if chars.len() != 3 || chars[1] != ' ' {
+            return Err(AocError::invalid(
+                "Expected a round of the form \"<A|B|C> <X|Y|Z>\"",
+                line,
+            ));
+        }
+        let shape = parse_shape(chars[0]).map_err(|e| e.at_column(1))?;
+        let encoded_shape = parse_encoded_shape(chars[2]).map_err(|e| e.at_column(3))?;
+        return Ok((shape, encoded_shape));
+    })
 }
 
 fn decode_part1(encoded_shape: &EncodedShape) -> Shape {
@@ -104,28 +106,32 @@ fn decode_part2(opponent_shape: &Shape, encoded_shape: &EncodedShape) -> Shape {
     }
 }
 
-fn main() {
-    let rounds = get_rounds();
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Shape, EncodedShape)>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_rounds(data)
+    }
 
-    // Part 1
-    {
+    fn part1(rounds: &Self::Input) -> Result<impl Display, AocError> {
         let mut sum_points = 0;
-        for (opponent_shape, player_encoded_shape) in &rounds {
+        for (opponent_shape, player_encoded_shape) in rounds {
             let player_shape = decode_part1(player_encoded_shape);
             let points = score_round(opponent_shape, &player_shape);
             sum_points += points;
         }
-        println!("Part 1: {}", sum_points); // Expected output: 15572
+        return Ok(sum_points);
     }
 
-    // Part 2
-    {
+    fn part2(rounds: &Self::Input) -> Result<impl Display, AocError> {
         let mut sum_points = 0;
-        for (opponent_shape, player_encoded_shape) in &rounds {
+        for (opponent_shape, player_encoded_shape) in rounds {
             let player_shape = decode_part2(opponent_shape, player_encoded_shape);
             let points = score_round(opponent_shape, &player_shape);
             sum_points += points;
         }
-        println!("Part 2: {}", sum_points); // Expected output: 16098
+        return Ok(sum_points);
     }
 }
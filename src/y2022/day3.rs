@@ -0,0 +1,118 @@
+use crate::error::AocError;
+use crate::parse::parse_lines;
+use crate::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Read rucksacks from the text file
+fn get_rucksacks(data: &str) -> Result<Vec<String>, AocError> {
+    parse_lines(data, |line| {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(AocError::invalid("Invalid item", &c.to_string()).at_column(i + 1));
+        }
+        if !line.len().is_multiple_of(2) {
+            return Err(AocError::invalid("Rucksack length is not even", line));
+        }
+        return Ok(line.to_string());
+    })
+}
+
+/// Finds the error character in a rucksack
+fn find_rucksack_error(rucksack: &str) -> Result<char, AocError> {
+    if !rucksack.len().is_multiple_of(2) {
+        return Err(AocError::invalid("Rucksack length is not even", rucksack));
+    }
+
+    let half_len = rucksack.len() / 2;
+    let first_compartment = rucksack[..half_len].to_string();
+    let second_compartment = rucksack[half_len..].to_string();
+
+    // keep set of characters in the first compartment
+    let mut set: HashSet<char> = HashSet::new();
+
+    for c in first_compartment.chars() {
+        set.insert(c);
+    }
+
+    for c in second_compartment.chars() {
+        if set.contains(&c) {
+            return Ok(c);
+        }
+    }
+    return Err(AocError::invalid(
+        "Could not find an item in both compartments",
+        rucksack,
+    ));
+}
+
+/// Get the priority of a character
+fn priority(c: char) -> i32 {
+    if c.is_lowercase() {
+        return (c as i32) - ('a' as i32) + 1;
+    } else {
+        return (c as i32) - ('A' as i32) + 27;
+    }
+}
+
+fn find_badge(rucksacks: (&str, &str, &str)) -> Result<char, AocError> {
+    // find an element that is in all three rucksacks
+
+    let mut set0: HashSet<char> = HashSet::new();
+    for c in rucksacks.0.chars() {
+        set0.insert(c);
+    }
+
+    let mut set1: HashSet<char> = HashSet::new();
+    for c in rucksacks.1.chars() {
+        if set0.contains(&c) {
+            set1.insert(c);
+        }
+    }
+
+    for c in rucksacks.2.chars() {
+        if set1.contains(&c) {
+            return Ok(c);
+        }
+    }
+
+    return Err(AocError::new("Could not find an item common to all three rucksacks"));
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_rucksacks(data)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<impl Display, AocError> {
+        let mut total_priority = 0;
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            let error = find_rucksack_error(rucksack).map_err(|e| e.at_line(i + 1, rucksack))?;
+            total_priority += priority(error);
+        }
+        return Ok(total_priority);
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<impl Display, AocError> {
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(AocError::new(format!(
+                "Expected the number of rucksacks to be divisible by 3, got {}",
+                rucksacks.len()
+            )));
+        }
+
+        let mut badge_total_priority = 0;
+        for i in 0..(rucksacks.len() / 3) {
+            let first_compartment = &rucksacks[3 * i];
+            let second_compartment = &rucksacks[3 * i + 1];
+            let third_compartment = &rucksacks[3 * i + 2];
+            let badge = find_badge((first_compartment, second_compartment, third_compartment))
+                .map_err(|e| e.at_line(3 * i + 1, first_compartment))?;
+            badge_total_priority += priority(badge);
+        }
+        return Ok(badge_total_priority);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error::AocError;
+use crate::parse::{parse_lines, parse_num, split_once};
+use crate::Solution;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Assignment {
+    start: i32,
+    end: i32,
+}
+
+/// Read assignments from the text file
+fn get_assignments(data: &str) -> Result<Vec<(Assignment, Assignment)>, AocError> {
+    parse_lines(data, |line| {
+        let (first, second) = split_once(line, ",")?;
+        let first_assignment = parse_assignment(first)?;
+        let second_assignment = parse_assignment(second)?;
+        return Ok((first_assignment, second_assignment));
+    })
+}
+
+/// Given a string like "123-456", parse it into an `Assignment``
+fn parse_assignment(s: &str) -> Result<Assignment, AocError> {
+    let (start, end) = split_once(s, "-")?;
+    let start: i32 = parse_num(start)?;
+    let end: i32 = parse_num(end)?;
+    return Ok(Assignment { start, end });
+}
+
+/// Given two assignments, determine if one is contained within the other
+fn contained(a: &Assignment, b: &Assignment) -> bool {
+    let a_in_b = a.start >= b.start && a.end <= b.end;
+    let b_in_a = b.start >= a.start && b.end <= a.end;
+    return a_in_b || b_in_a;
+}
+
+/// Given two assignments, determine if they overlap
+fn overlap(a: &Assignment, b: &Assignment) -> bool {
+    let a_before_b = a.end < b.start;
+    let b_before_a = b.end < a.start;
+    return !a_before_b && !b_before_a;
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_assignments(data)
+    }
+
+    fn part1(assignments: &Self::Input) -> Result<impl Display, AocError> {
+        let mut contained_count = 0;
+        for (a, b) in assignments {
+            if contained(a, b) {
+                contained_count += 1;
+            }
+        }
+        return Ok(contained_count);
+    }
+
+    fn part2(assignments: &Self::Input) -> Result<impl Display, AocError> {
+        let mut overlap_count = 0;
+        for (a, b) in assignments {
+            if overlap(a, b) {
+                overlap_count += 1;
+            }
+        }
+        return Ok(overlap_count);
+    }
+}
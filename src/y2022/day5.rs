@@ -0,0 +1,730 @@
+use crate::error::AocError;
+use crate::input::{Paragraph, Reader};
+use crate::parse::{parse_num, parse_paragraph_lines};
+use crate::{Generate, Solution, Trace};
+use std::fmt::{self, Display};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    num: usize,
+    source: usize,
+    dest: usize,
+}
+
+/// Formats the move as in the input, with stacks numbered from 1.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.num, self.source + 1, self.dest + 1)
+    }
+}
+
+/// Stacks of crates, each listed from bottom to top.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stacks {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    /// Parse the stacks from a drawing like the one below, where the last
+    /// line numbers the stacks from 1.
+    ///
+    /// ```text
+    ///     [D]
+    /// [N] [C]
+    /// [Z] [M] [P]
+    ///  1   2   3
+    /// ```
+    fn parse(drawing: &Paragraph) -> Result<Self, AocError> {
+        let (footer, rows) = drawing
+            .lines
+            .split_last()
+            .ok_or_else(|| AocError::new("Expected a drawing of the crate stacks"))?;
+        let footer_line = drawing.line + rows.len();
+
+        // the footer must number the stacks 1, 2, 3, ...
+        let mut num_stacks = 0;
+        for label in footer.split_whitespace() {
+            if parse_num::<usize>(label).ok() != Some(num_stacks + 1) {
+                let e = AocError::invalid(format!("Expected stack number {}", num_stacks + 1), label);
+                return Err(e.at_line(footer_line, footer));
+            }
+            num_stacks += 1;
+        }
+        if num_stacks == 0 {
+            return Err(AocError::new("Expected stack numbers").at_line(footer_line, footer));
+        }
+
+        let mut stacks: Vec<Vec<char>> = vec![Vec::new(); num_stacks];
+        // read rows from the bottom up, so that crates are pushed in order
+        for (i, row) in rows.iter().enumerate().rev() {
+            let crates = parse_crate_row(row, num_stacks).map_err(|e| e.at_line(drawing.line + i, row))?;
+            for (stack, c) in crates.into_iter().enumerate() {
+                match c {
+                    Some(c) if stacks[stack].len() == rows.len() - 1 - i => stacks[stack].push(c),
+                    Some(c) => {
+                        let e = AocError::invalid("Crate above an empty space", &format!("[{}]", c));
+                        return Err(e.at_column(4 * stack + 1).at_line(drawing.line + i, row));
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        return Ok(Self { stacks });
+    }
+
+    /// Concatenates the characters at the top of each stack into a string
+    fn tops(&self) -> Result<String, AocError> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(i, stack)| {
+                stack
+                    .last()
+                    .copied()
+                    .ok_or_else(|| AocError::new(format!("Stack {} is empty", i + 1)))
+            })
+            .collect()
+    }
+}
+
+/// Draws the stacks in the same format as the input, e.g.
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let cells: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => String::from("   "),
+                })
+                .collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        let labels: Vec<String> = (1..=self.stacks.len()).map(|i| format!("{:^3}", i)).collect();
+        writeln!(f, "{}", labels.join(" "))
+    }
+}
+
+/// Parse one row of crates in a drawing, e.g. "[Z] [M] [P]". Returns the crate
+/// in each stack, if any.
+fn parse_crate_row(row: &str, num_stacks: usize) -> Result<Vec<Option<char>>, AocError> {
+    let chars: Vec<char> = row.chars().collect();
+    if chars.len() > 4 * num_stacks - 1 {
+        return Err(AocError::new(format!("Expected at most {} stacks", num_stacks)).at_column(4 * num_stacks));
+    }
+
+    let mut crates: Vec<Option<char>> = Vec::new();
+    for i in 0..num_stacks {
+        // each crate takes up 3 characters, and is followed by a space
+        let cell: String = chars.iter().skip(4 * i).take(3).collect();
+        let separator = chars.get(4 * i + 3).copied().unwrap_or(' ');
+
+        let mut cell_chars = cell.chars();
+        let c = match (cell_chars.next(), cell_chars.next(), cell_chars.next()) {
+            (None, _, _) | (Some(' '), Some(' '), Some(' ')) => None,
+            (Some('['), Some(c), Some(']')) if c.is_ascii_alphabetic() => Some(c),
+            _ => return Err(AocError::invalid("Invalid crate", &cell).at_column(4 * i + 1)),
+        };
+        if separator != ' ' {
+            return Err(
+                AocError::invalid("Expected a space between stacks", &separator.to_string()).at_column(4 * i + 4),
+            );
+        }
+        crates.push(c);
+    }
+    return Ok(crates);
+}
+
+/// Parse the moves from a paragraph of the input
+fn get_moves(paragraph: &Paragraph, num_stacks: usize) -> Result<Vec<Move>, AocError> {
+    parse_paragraph_lines(paragraph, |line| parse_move(line, num_stacks))
+}
+
+/// Parse a move from a line of the form "move <num> from <source> to <dest>"
+fn parse_move(line: &str, num_stacks: usize) -> Result<Move, AocError> {
+    let parts: Vec<&str> = line.split(' ').collect();
+    if parts.len() != 6 || parts[0] != "move" || parts[2] != "from" || parts[4] != "to" {
+        return Err(AocError::invalid(
+            "Expected a move of the form \"move <num> from <source> to <dest>\"",
+            line,
+        ));
+    }
+
+    // stacks are numbered from 1 in the input, but indexed from 0
+    let parse_stack = |s: &str| -> Result<usize, AocError> {
+        match parse_num::<usize>(s)? {
+            n if (1..=num_stacks).contains(&n) => Ok(n - 1),
+            _ => Err(AocError::invalid("Invalid stack number", s)),
+        }
+    };
+
+    return Ok(Move {
+        num: parse_num(parts[1])?,
+        source: parse_stack(parts[3])?,
+        dest: parse_stack(parts[5])?,
+    });
+}
+
+/// A move executed by a crane. The crates it removed from the source stack
+/// are kept in the crane's log, starting at `offset`, so that it can be undone.
+#[derive(Copy, Clone, Debug)]
+struct Step {
+    m: Move,
+    offset: usize,
+}
+
+/// Returns mutable references to two different stacks.
+fn get_pair_mut(stacks: &mut [Vec<char>], a: usize, b: usize) -> (&mut Vec<char>, &mut Vec<char>) {
+    assert_ne!(a, b, "Expected two different stacks");
+    if a < b {
+        let (left, right) = stacks.split_at_mut(b);
+        return (&mut left[a], &mut right[0]);
+    }
+    let (left, right) = stacks.split_at_mut(a);
+    return (&mut right[0], &mut left[b]);
+}
+
+/// The way a model of crane lifts crates from one stack onto another.
+pub trait CraneModel {
+    /// Place the crates removed from the source stack, listed from bottom to
+    /// top, onto the destination stack. All of them must be placed, so that
+    /// the move can be undone.
+    fn place(&self, crates: &[char], dest: &mut Vec<char>);
+}
+
+/// Moves crates one at a time, as in Part 1, so their order is reversed.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CrateMover9000;
+
+impl CraneModel for CrateMover9000 {
+    fn place(&self, crates: &[char], dest: &mut Vec<char>) {
+        dest.extend(crates.iter().rev());
+    }
+}
+
+/// Moves all the crates at once, as in Part 2, so their order is kept.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CrateMover9001;
+
+impl CraneModel for CrateMover9001 {
+    fn place(&self, crates: &[char], dest: &mut Vec<char>) {
+        dest.extend_from_slice(crates);
+    }
+}
+
+/// Moves up to `capacity` crates at once, so a large move is split into
+/// several lifts from the top of the stack. Each lift keeps its order.
+#[derive(Copy, Clone, Debug)]
+pub struct CappedCrane {
+    capacity: usize,
+}
+
+impl CappedCrane {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Crane capacity must be positive");
+        Self { capacity }
+    }
+}
+
+impl CraneModel for CappedCrane {
+    fn place(&self, crates: &[char], dest: &mut Vec<char>) {
+        // the first lift takes the crates at the top
+        for lift in crates.rchunks(self.capacity) {
+            dest.extend_from_slice(lift);
+        }
+    }
+}
+
+/// Moves all the crates at once, but rotates them while lifting, so the top
+/// crate ends up at the bottom of the chunk.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RotatingCrane;
+
+impl CraneModel for RotatingCrane {
+    fn place(&self, crates: &[char], dest: &mut Vec<char>) {
+        if let Some((top, rest)) = crates.split_last() {
+            dest.push(*top);
+            dest.extend_from_slice(rest);
+        }
+    }
+}
+
+/// A crane rearranging stacks of crates. Every move is checked before it is
+/// executed, and executed moves are kept in a history so that they can be
+/// undone, and redone by replaying to a later step.
+#[derive(Clone, Debug)]
+pub struct Crane<M: CraneModel> {
+    stacks: Stacks,
+    model: M,
+    history: Vec<Step>,
+    /// Crates removed by each step in `history`, from bottom to top
+    log: Vec<char>,
+    /// If false, moves are not recorded and cannot be undone
+    keep_history: bool,
+    /// Number of steps in `history` that are currently applied
+    position: usize,
+}
+
+impl<M: CraneModel> Crane<M> {
+    pub fn new(stacks: Stacks, model: M) -> Self {
+        Self {
+            stacks,
+            model,
+            history: Vec::new(),
+            log: Vec::new(),
+            keep_history: true,
+            position: 0,
+        }
+    }
+
+    /// Create a crane that does not record its moves, e.g. to execute a long
+    /// procedure quickly. Its moves cannot be undone.
+    pub fn without_history(stacks: Stacks, model: M) -> Self {
+        Self {
+            keep_history: false,
+            ..Self::new(stacks, model)
+        }
+    }
+
+    /// Returns the current stacks.
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// Returns the number of moves executed so far, not counting undone ones.
+    pub fn step(&self) -> usize {
+        self.position
+    }
+
+    /// Returns an error if the move cannot be executed on the current stacks.
+    fn check(&self, m: &Move) -> Result<(), AocError> {
+        let num_stacks = self.stacks.stacks.len();
+        let invalid = |message: String| Err(AocError::invalid(message, &m.to_string()));
+
+        for stack in [m.source, m.dest] {
+            if stack >= num_stacks {
+                return invalid(format!(
+                    "Stack {} does not exist, there are {} stacks",
+                    stack + 1,
+                    num_stacks
+                ));
+            }
+        }
+        if m.source == m.dest {
+            return invalid(format!("Cannot move crates from stack {} onto itself", m.source + 1));
+        }
+        let available = self.stacks.stacks[m.source].len();
+        if m.num > available {
+            return invalid(format!(
+                "Cannot move {} crates from stack {}, which has {}",
+                m.num,
+                m.source + 1,
+                available
+            ));
+        }
+        return Ok(());
+    }
+
+    /// Move the crates. The crates are placed straight from the top of the
+    /// source stack, which is then shortened, so no buffer is needed.
+    fn apply(&mut self, m: &Move) {
+        let (source, dest) = get_pair_mut(&mut self.stacks.stacks, m.source, m.dest);
+        let at = source.len() - m.num;
+        self.model.place(&source[at..], dest);
+        source.truncate(at);
+    }
+
+    /// Execute the move, or return an error describing why it cannot be
+    /// executed. Any undone moves are discarded from the history.
+    pub fn execute(&mut self, m: &Move) -> Result<(), AocError> {
+        self.check(m)?;
+        if !self.keep_history {
+            self.apply(m);
+            self.position += 1;
+            return Ok(());
+        }
+
+        // discard undone moves, then record the crates about to be removed
+        if let Some(undone) = self.history.get(self.position) {
+            self.log.truncate(undone.offset);
+        }
+        self.history.truncate(self.position);
+        let source = &self.stacks.stacks[m.source];
+        self.history.push(Step {
+            m: *m,
+            offset: self.log.len(),
+        });
+        self.log.extend_from_slice(&source[source.len() - m.num..]);
+
+        self.apply(m);
+        self.position += 1;
+        return Ok(());
+    }
+
+    /// Undo the last executed move and return it, or `None` if there are no
+    /// moves to undo.
+    pub fn undo(&mut self) -> Option<&Move> {
+        if self.position == 0 || !self.keep_history {
+            return None;
+        }
+        self.position -= 1;
+
+        let step = &self.history[self.position];
+        let (source, dest) = get_pair_mut(&mut self.stacks.stacks, step.m.source, step.m.dest);
+        dest.truncate(dest.len() - step.m.num);
+        source.extend_from_slice(&self.log[step.offset..step.offset + step.m.num]);
+        return Some(&step.m);
+    }
+
+    /// Redo the last undone move and return it, or `None` if there are no
+    /// moves to redo.
+    pub fn redo(&mut self) -> Option<&Move> {
+        // the stacks are as they were when the move was first executed, so
+        // the same crates are removed and the log is still correct
+        let m = self.history.get(self.position)?.m;
+        self.apply(&m);
+        self.position += 1;
+        return Some(&self.history[self.position - 1].m);
+    }
+
+    /// Undo or redo moves until exactly `step` moves from the history are
+    /// applied. Step 0 is the starting stacks.
+    pub fn replay_to(&mut self, step: usize) -> Result<(), AocError> {
+        if !self.keep_history && step != self.position {
+            return Err(AocError::new("Cannot replay a crane that does not keep a history"));
+        }
+        if self.keep_history && step > self.history.len() {
+            return Err(AocError::new(format!(
+                "Cannot replay to step {}, only {} moves have been executed",
+                step,
+                self.history.len()
+            )));
+        }
+        while self.position > step {
+            self.undo();
+        }
+        while self.position < step {
+            self.redo();
+        }
+        return Ok(());
+    }
+}
+
+/// The starting stacks of crates and the moves of the rearrangement procedure
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+    /// Line number of the first move in the input
+    moves_line: usize,
+}
+
+impl Procedure {
+    /// Returns the starting stacks.
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Execute all moves with the given model of crane, and return the crates
+    /// at the top of each stack. Invalid moves are reported at their line in
+    /// the input.
+    pub fn run(&self, model: impl CraneModel) -> Result<String, AocError> {
+        self.trace(model, |_, _| {})
+    }
+
+    /// Same as `run`, but also calls `f` with the starting stacks, and with
+    /// each move and the stacks after it.
+    pub fn trace(&self, model: impl CraneModel, mut f: impl FnMut(Option<&Move>, &Stacks)) -> Result<String, AocError> {
+        let mut crane = Crane::without_history(self.stacks.clone(), model);
+        f(None, crane.stacks());
+        for (i, m) in self.moves.iter().enumerate() {
+            crane
+                .execute(m)
+                .map_err(|e| e.at_line(self.moves_line + i, &m.to_string()))?;
+            f(Some(m), crane.stacks());
+        }
+        return crane.stacks().tops();
+    }
+}
+
+/// Parse the input, which is a drawing of the stacks followed by a blank line
+/// and the list of moves.
+fn get_procedure(data: &str) -> Result<Procedure, AocError> {
+    let reader = Reader::new(data);
+    let mut paragraphs = reader.paragraphs();
+
+    let drawing = paragraphs
+        .next()
+        .ok_or_else(|| AocError::new("Expected a drawing of the crate stacks"))?;
+    let stacks = Stacks::parse(&drawing)?;
+
+    let (moves, moves_line) = match paragraphs.next() {
+        Some(paragraph) => (get_moves(&paragraph, stacks.stacks.len())?, paragraph.line),
+        None => (Vec::new(), 0),
+    };
+    if let Some(paragraph) = paragraphs.next() {
+        return Err(AocError::new("Unexpected text after the moves").at_line(paragraph.line, paragraph.lines[0]));
+    }
+
+    return Ok(Procedure {
+        stacks,
+        moves,
+        moves_line,
+    });
+}
+
+/// Generate a random input with the given number of stacks, each starting
+/// with `height` crates, and the given number of moves. Every move is valid,
+/// and moves lift up to all but one of the crates of a stack, so that large
+/// inputs stress bulk moves while no stack is ever left empty and both parts
+/// have an answer. The same seed always gives the same input.
+pub fn generate_input(num_stacks: usize, height: usize, num_moves: usize, seed: u64) -> String {
+    assert!(num_stacks >= 2, "Expected at least two stacks");
+
+    // xorshift64, which is plenty random for benchmarking
+    let mut state = seed.max(1);
+    let mut random = |n: usize| -> usize {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % n as u64) as usize
+    };
+
+    let stacks = Stacks {
+        stacks: (0..num_stacks)
+            .map(|_| (0..height).map(|_| (b'A' + random(26) as u8) as char).collect())
+            .collect(),
+    };
+    let mut text = format!("{}\n", stacks);
+
+    let mut sizes: Vec<usize> = vec![height; num_stacks];
+    // some stack must have a crate to spare, which is the case as long as
+    // the stacks start with two crates each
+    let num_moves = if height >= 2 { num_moves } else { 0 };
+    for _ in 0..num_moves {
+        let mut source = random(num_stacks);
+        while sizes[source] < 2 {
+            source = random(num_stacks);
+        }
+        let dest = (source + 1 + random(num_stacks - 1)) % num_stacks;
+        let num = 1 + random(sizes[source] - 1);
+        sizes[source] -= num;
+        sizes[dest] += num;
+
+        let m = Move { num, source, dest };
+        text.push_str(&format!("{}\n", m));
+    }
+    return text;
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_procedure(data)
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<impl Display, AocError> {
+        return procedure.run(CrateMover9000);
+    }
+
+    fn part2(procedure: &Self::Input) -> Result<impl Display, AocError> {
+        return procedure.run(CrateMover9001);
+    }
+}
+
+impl Trace for Day5 {
+    fn trace(procedure: &Self::Input, part: usize, mut f: impl FnMut(String)) -> Result<(), AocError> {
+        let show_step = |m: Option<&Move>, stacks: &Stacks| match m {
+            Some(m) => f(format!("{}\n{}", m, stacks)),
+            None => f(format!("start\n{}", stacks)),
+        };
+        match part {
+            1 => procedure.trace(CrateMover9000, show_step)?,
+            _ => procedure.trace(CrateMover9001, show_step)?,
+        };
+        return Ok(());
+    }
+}
+
+impl Generate for Day5 {
+    /// Generates nine stacks of 50 crates and `size` moves.
+    fn generate(size: usize) -> String {
+        generate_input(9, 50, size, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2\n",
+    );
+
+    /// Execute every move of the example, and return the crane along with
+    /// the stacks at each step, starting with the initial stacks.
+    fn run_example() -> (Crane<CrateMover9000>, Vec<Stacks>) {
+        let procedure = get_procedure(EXAMPLE).unwrap();
+        let mut crane = Crane::new(procedure.stacks().clone(), CrateMover9000);
+        let mut snapshots = vec![crane.stacks().clone()];
+        for m in procedure.moves() {
+            crane.execute(m).unwrap();
+            snapshots.push(crane.stacks().clone());
+        }
+        return (crane, snapshots);
+    }
+
+    #[test]
+    fn replay_to_earlier_and_later_steps() {
+        let (mut crane, snapshots) = run_example();
+        assert_eq!(crane.stacks().tops().unwrap(), "CMZ");
+
+        crane.replay_to(1).unwrap();
+        assert_eq!(crane.step(), 1);
+        assert_eq!(crane.stacks(), &snapshots[1]);
+
+        crane.replay_to(0).unwrap();
+        assert_eq!(crane.stacks(), &snapshots[0]);
+        assert_eq!(crane.stacks().tops().unwrap(), "NDP");
+
+        crane.replay_to(3).unwrap();
+        assert_eq!(crane.step(), 3);
+        assert_eq!(crane.stacks(), &snapshots[3]);
+
+        crane.replay_to(4).unwrap();
+        assert_eq!(crane.stacks(), &snapshots[4]);
+    }
+
+    #[test]
+    fn execute_after_undo_drops_redo() {
+        let (mut crane, snapshots) = run_example();
+        crane.replay_to(1).unwrap();
+
+        let m = Move {
+            num: 1,
+            source: 0,
+            dest: 2,
+        };
+        crane.execute(&m).unwrap();
+        assert_eq!(crane.step(), 2);
+        assert!(crane.redo().is_none());
+        assert!(crane.replay_to(3).is_err());
+
+        // undoing the new move goes back to the shared first step
+        assert_eq!(crane.undo(), Some(&m));
+        assert_eq!(crane.stacks(), &snapshots[1]);
+    }
+
+    #[test]
+    fn replay_past_end_is_error() {
+        let (mut crane, snapshots) = run_example();
+        crane.replay_to(2).unwrap();
+
+        assert!(crane.replay_to(5).is_err());
+        assert_eq!(crane.step(), 2);
+        assert_eq!(crane.stacks(), &snapshots[2]);
+    }
+
+    #[test]
+    fn capped_crane_splits_large_moves() {
+        let stacks = Stacks {
+            stacks: vec![vec!['A', 'B', 'C'], vec!['Z']],
+        };
+        let mut crane = Crane::new(stacks, CappedCrane::new(2));
+        crane
+            .execute(&Move {
+                num: 3,
+                source: 0,
+                dest: 1,
+            })
+            .unwrap();
+        // the top two crates are lifted first, then the last one
+        assert_eq!(crane.stacks().stacks, vec![vec![], vec!['Z', 'B', 'C', 'A']]);
+
+        let procedure = get_procedure(EXAMPLE).unwrap();
+        assert_eq!(procedure.run(CappedCrane::new(2)).unwrap(), "MCZ");
+    }
+
+    #[test]
+    fn rotating_crane_on_example() {
+        let procedure = get_procedure(EXAMPLE).unwrap();
+        assert_eq!(procedure.run(RotatingCrane).unwrap(), "CMN");
+    }
+
+    #[test]
+    fn generated_inputs_have_answers() {
+        for (num_stacks, height, num_moves) in [(2, 2, 10), (3, 5, 1000), (9, 50, 1000), (9, 50, 100_000)] {
+            let input = generate_input(num_stacks, height, num_moves, 1);
+            let procedure = Day5::parse(&input).unwrap();
+            assert_eq!(procedure.moves().len(), num_moves);
+            assert_eq!(Day5::part1(&procedure).unwrap().to_string().len(), num_stacks);
+            assert_eq!(Day5::part2(&procedure).unwrap().to_string().len(), num_stacks);
+        }
+    }
+
+    /// Parse a drawing of the stacks on its own, without any moves.
+    fn parse_drawing(text: &str) -> Stacks {
+        let reader = Reader::new(text);
+        let drawing = reader.paragraphs().next().unwrap();
+        return Stacks::parse(&drawing).unwrap();
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let all_stacks = [
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+            // uneven, with empty stacks at the start, middle and end
+            vec![vec![], vec!['A'], vec![], vec!['B', 'C', 'D', 'E'], vec![]],
+            vec![vec![], vec![]],
+        ];
+        for stacks in all_stacks {
+            let stacks = Stacks { stacks };
+            assert_eq!(parse_drawing(&stacks.to_string()), stacks);
+        }
+        assert_eq!(
+            parse_drawing(EXAMPLE).to_string(),
+            EXAMPLE.split("\n\n").next().unwrap().to_string() + "\n"
+        );
+    }
+
+    #[test]
+    fn trace_shows_every_move() {
+        let (_, snapshots) = run_example();
+        let procedure = get_procedure(EXAMPLE).unwrap();
+        let mut frames: Vec<String> = Vec::new();
+        Day5::trace(&procedure, 1, |frame| frames.push(frame)).unwrap();
+
+        // the starting stacks, then one diagram per move
+        assert_eq!(frames.len(), procedure.moves().len() + 1);
+        assert_eq!(frames[0], format!("start\n{}", snapshots[0]));
+        for (i, m) in procedure.moves().iter().enumerate() {
+            let (label, diagram) = frames[i + 1].split_once('\n').unwrap();
+            assert_eq!(label, m.to_string());
+            assert_eq!(parse_drawing(diagram), snapshots[i + 1]);
+        }
+    }
+}
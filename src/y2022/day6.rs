@@ -0,0 +1,69 @@
+use crate::error::AocError;
+use crate::input::Reader;
+use crate::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// Given a string slice, return true if all characters are unique
+fn all_unique_chars(s: &str) -> bool {
+    let mut chars: HashSet<char> = HashSet::new();
+    for c in s.chars() {
+        if !chars.insert(c) {
+            return false;
+        }
+    }
+    return true;
+}
+
+/// Given a string slice, returns index at which the first contiguous run of
+/// `size` unique characters is found. i.e. if the output is `i`, then
+/// `s[i..i+size]` will be a contiguous run of unique characters.
+fn find_unique_run(size: usize, s: &str) -> Result<usize, AocError> {
+    // the last window ends on the last character
+    if s.len() >= size {
+        for i in 0..=s.len() - size {
+            let slice = &s[i..i + size];
+            if all_unique_chars(slice) {
+                return Ok(i);
+            }
+        }
+    }
+    return Err(AocError::new(format!(
+        "Could not find contiguous run of {size} unique characters"
+    )));
+}
+
+const SOP_MARKER_SIZE: usize = 4; // start-of-packet marker size
+const SOM_MARKER_SIZE: usize = 14; // start-of-message marker size
+
+/// Returns the index of the first character after the start-of-packet marker
+fn find_packet_start(data: &str) -> Result<usize, AocError> {
+    let sop_marker_index = find_unique_run(SOP_MARKER_SIZE, data)?;
+    return Ok(sop_marker_index + SOP_MARKER_SIZE);
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let data = Reader::new(data).text();
+        if let Some((i, c)) = data.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(AocError::invalid("Invalid character", &c.to_string()).at_column(i + 1));
+        }
+        return Ok(data);
+    }
+
+    fn part1(data: &Self::Input) -> Result<impl Display, AocError> {
+        let packet_start = find_packet_start(data)?;
+        return Ok(packet_start);
+    }
+
+    fn part2(data: &Self::Input) -> Result<impl Display, AocError> {
+        let packet_start = find_packet_start(data)?;
+        let som_marker_index = find_unique_run(SOM_MARKER_SIZE, &data[packet_start..])?;
+        let message_start = som_marker_index + SOM_MARKER_SIZE + packet_start;
+        return Ok(message_start);
+    }
+}
@@ -1,5 +1,8 @@
-/// File containing puzzle data
-const DATA: &'static str = "data/22day7.txt";
+use crate::error::AocError;
+use crate::input::Reader;
+use crate::parse::parse_num;
+use crate::Solution;
+use std::fmt::Display;
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -10,7 +13,7 @@ struct File {
 
 #[allow(dead_code)]
 #[derive(Debug)]
-struct Dir {
+pub struct Dir {
     name: String, // unused
     size: i32,    // this must be updated when files and subdirs are added
     files: Vec<File>,
@@ -29,6 +32,7 @@ impl Dir {
 }
 
 /// Encodes an executed bash instruction or an output line of an `ls` call.
+#[allow(dead_code, clippy::upper_case_acronyms)]
 #[derive(Debug)]
 enum Instruction {
     CDROOT,       // cd to the root directory
@@ -40,28 +44,26 @@ enum Instruction {
 }
 
 /// Parses an `Instruction` from a line of the input file.
-fn parse_instruction(line: &str) -> Instruction {
+fn parse_instruction(line: &str) -> Result<Instruction, AocError> {
     let words: Vec<&str> = line.split(' ').collect();
-    match words[0] {
-        "$" => match words[1] {
-            "cd" => match words[2] {
-                "/" => Instruction::CDROOT,
-                ".." => Instruction::CDOUT,
-                _ => Instruction::CDIN(words[2].to_string()),
-            },
-            "ls" => Instruction::LS,
-            _ => panic!("Unknown instruction: {}", words[1]),
-        },
-        "dir" => Instruction::DIR(words[1].to_string()),
-        _ => Instruction::FILE(File {
-            size: words[0].parse().unwrap(),
-            name: words[1].to_string(),
+    let instruction = match words[..] {
+        ["$", "cd", "/"] => Instruction::CDROOT,
+        ["$", "cd", ".."] => Instruction::CDOUT,
+        ["$", "cd", name] => Instruction::CDIN(name.to_string()),
+        ["$", "ls"] => Instruction::LS,
+        ["$", command, ..] => return Err(AocError::invalid("Unknown instruction", command)),
+        ["dir", name] => Instruction::DIR(name.to_string()),
+        [size, name] => Instruction::FILE(File {
+            size: parse_num(size)?,
+            name: name.to_string(),
         }),
-    }
+        _ => return Err(AocError::invalid("Expected a command or an `ls` output line", line)),
+    };
+    return Ok(instruction);
 }
 
 /// Execute a line instruction on the current state of the file system.
-fn execute_instruction(dir_stack: &mut Vec<Dir>, instruction: Instruction) {
+fn execute_instruction(dir_stack: &mut Vec<Dir>, instruction: Instruction) -> Result<(), AocError> {
     match instruction {
         Instruction::CDIN(name) => {
             // cd into the given directory
@@ -69,6 +71,9 @@ fn execute_instruction(dir_stack: &mut Vec<Dir>, instruction: Instruction) {
         }
         Instruction::CDOUT => {
             // on the way out, add the current directory as a subdir of the parent
+            if dir_stack.len() < 2 {
+                return Err(AocError::new("Cannot cd out of the root directory"));
+            }
             let dir = dir_stack.pop().unwrap();
             let parent = dir_stack.last_mut().unwrap();
             parent.size += dir.size;
@@ -82,33 +87,38 @@ fn execute_instruction(dir_stack: &mut Vec<Dir>, instruction: Instruction) {
         }
         _ => {} // do nothing for all other instructions
     };
+    return Ok(());
 }
 
 /// Create the file system.
-fn create_fs() -> Dir {
-    let data = std::fs::read_to_string(DATA).unwrap();
-
+fn create_fs(data: &str) -> Result<Dir, AocError> {
     // construct the file system by executing the instructions in the input file
     let mut dir_stack = vec![Dir::new(String::from("/"))];
-    for line in data.split('\n') {
-        let instruction = parse_instruction(line);
-        execute_instruction(&mut dir_stack, instruction);
+    for (i, line) in Reader::new(data).lines().enumerate() {
+        parse_instruction(line)
+            .and_then(|instruction| execute_instruction(&mut dir_stack, instruction))
+            .map_err(|e| e.at_line(i + 1, line))?;
     }
 
     // if the directory stack is non-empty, we need to cd out to the "/" root
     // directory and add subdirectories along the way
     while dir_stack.len() > 1 {
-        execute_instruction(&mut dir_stack, Instruction::CDOUT);
+        execute_instruction(&mut dir_stack, Instruction::CDOUT)?;
     }
-    return dir_stack.pop().unwrap();
+    return Ok(dir_stack.pop().unwrap());
 }
 
-fn main() {
-    // initialize the file system
-    let fs = create_fs();
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Dir;
+
+    /// Create the file system.
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        create_fs(data)
+    }
 
-    // Part 1
-    {
+    fn part1(fs: &Self::Input) -> Result<impl Display, AocError> {
         // recursively search for directories with size less than or equal to
         // 100,000 and sum their sizes
         fn sum_dir_sizes(dir: &Dir, limit: i32) -> i32 {
@@ -122,12 +132,11 @@ fn main() {
             return total;
         }
 
-        let sum = sum_dir_sizes(&fs, 100_000);
-        println!("Part 1: {}", sum); // Expected output: 1989474
+        let sum = sum_dir_sizes(fs, 100_000);
+        return Ok(sum);
     }
 
-    // Part 2
-    {
+    fn part2(fs: &Self::Input) -> Result<impl Display, AocError> {
         let total_size = fs.size; // total size is 41,072,511
         let threshold_size = total_size - 40_000_000; // need to find a folder whose size is >= 1,072,511
 
@@ -149,7 +158,7 @@ fn main() {
             return smallest;
         }
 
-        let smallest = find_dir_smallest(&fs, threshold_size).unwrap();
-        println!("Part 2: {}", smallest); // Expected output: 1111607
+        let smallest = find_dir_smallest(fs, threshold_size).unwrap();
+        return Ok(smallest);
     }
 }
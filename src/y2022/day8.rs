@@ -0,0 +1,139 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::vec::{Direction, Vec2d};
+use crate::Solution;
+use std::fmt::Display;
+
+/// Parse heights from the input.
+fn get_heights(data: &str) -> Result<Grid<i32>, AocError> {
+    Grid::parse(data, |c| match c.to_digit(10) {
+        Some(digit) => Ok(digit as i32),
+        None => Err(AocError::invalid("Invalid height", &c.to_string())),
+    })
+}
+
+/// `start` is a point on the edge of the map, and `dir` points inwards.
+/// March along the grid in the direction of `dir`, marking all points as visible
+/// if they are higher than the highest point seen so far.
+fn mark_visibilities(visible: &mut Grid<bool>, height: &Grid<i32>, start: Vec2d, dir: Direction) {
+    let mut highest_so_far = -1;
+    let mut pos = start;
+    while let Some(&curr_height) = height.get(pos) {
+        if curr_height > highest_so_far {
+            highest_so_far = curr_height;
+            visible[pos] = true;
+        }
+        pos += dir.to_vec();
+    }
+}
+
+/// Returns the points on the edge of the map from which we can march across
+/// the grid in the given direction, i.e. the edge facing the opposite way.
+fn edge_starts(height: &Grid<i32>, dir: Direction) -> impl Iterator<Item = Vec2d> + '_ {
+    height
+        .positions()
+        .filter(move |&pos| !height.contains(pos - dir.to_vec()))
+}
+
+fn get_visibilities(height: &Grid<i32>) -> Grid<bool> {
+    let mut visible = Grid::new(height.width(), height.height(), false);
+
+    for dir in Direction::CARDINAL {
+        for start in edge_starts(height, dir) {
+            mark_visibilities(&mut visible, height, start, dir);
+        }
+    }
+
+    return visible;
+}
+
+fn count_visibilities(visible: &Grid<bool>) -> i32 {
+    let mut count = 0;
+    for (_, visible) in visible.iter() {
+        if *visible {
+            count += 1;
+        }
+    }
+    return count;
+}
+
+/// `start` is a point on the edge of the map, and `dir` points inwards.
+/// March along the grid in the direction of `dir`, multiplying the score of each
+/// point by its viewing distance looking back towards `start`.
+///
+/// The viewing distance is found with a monotonic stack holding the trees that
+/// can still block the view of later trees, so that the whole line takes linear time.
+fn mark_viewing_distances(score: &mut Grid<u64>, height: &Grid<i32>, start: Vec2d, dir: Direction) {
+    // (step, height) of each potential blocker, with decreasing heights
+    let mut blockers: Vec<(usize, i32)> = Vec::new();
+    let mut pos = start;
+    let mut step = 0;
+    while let Some(&curr_height) = height.get(pos) {
+        // trees shorter than this one cannot block the view of later trees
+        while blockers.last().is_some_and(|&(_, h)| h < curr_height) {
+            blockers.pop();
+        }
+        let viewing_distance = match blockers.last() {
+            Some(&(blocker_step, _)) => step - blocker_step,
+            // we can see all the way to the edge
+            None => step,
+        };
+        score[pos] *= viewing_distance as u64;
+
+        blockers.push((step, curr_height));
+        pos += dir.to_vec();
+        step += 1;
+    }
+}
+
+/// Returns the scenic score of every tree. Scores are products of four
+/// viewing distances, so they overflow `i32` on large forests.
+fn get_scenic_scores(height: &Grid<i32>) -> Grid<u64> {
+    let mut score = Grid::new(height.width(), height.height(), 1);
+
+    for dir in Direction::CARDINAL {
+        for start in edge_starts(height, dir) {
+            mark_viewing_distances(&mut score, height, start, dir);
+        }
+    }
+
+    return score;
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<i32>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_heights(data)
+    }
+
+    fn part1(height: &Self::Input) -> Result<impl Display, AocError> {
+        let visible = get_visibilities(height);
+
+        let count = count_visibilities(&visible);
+        return Ok(count);
+    }
+
+    fn part2(height: &Self::Input) -> Result<impl Display, AocError> {
+        let score = get_scenic_scores(height);
+
+        let max_score = score.iter().map(|(_, score)| *score).max().unwrap_or(0);
+        return Ok(max_score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenic_score_does_not_overflow() {
+        // a single tall tree in the middle of a flat 601x601 forest sees 300
+        // trees in every direction
+        let mut height = Grid::new(601, 601, 0);
+        height[Vec2d { x: 300, y: 300 }] = 9;
+        assert_eq!(Day8::part2(&height).unwrap().to_string(), "8100000000");
+    }
+}
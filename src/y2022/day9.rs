@@ -0,0 +1,197 @@
+use crate::error::AocError;
+use crate::grid::SparseGrid;
+use crate::parse::{parse_lines, parse_num, split_once};
+use crate::render::{Cell, Color, Renderer};
+use crate::vec::{Direction, Rect, Vec2d};
+use crate::{Animate, AnimateOptions, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+struct Rope {
+    length: usize,
+    knots: Vec<Vec2d>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        let mut knots: Vec<Vec2d> = Vec::new();
+        for _ in 0..length {
+            knots.push(Vec2d::default());
+        }
+        Self { length, knots }
+    }
+
+    /// Returns true if the points are touching
+    fn is_touching(a: &Vec2d, b: &Vec2d) -> bool {
+        a.chebyshev(*b) <= 1
+    }
+
+    /// Returns the new position of a tail knot given the new position of the head knot
+    fn move_towards(tail: &Vec2d, head: &Vec2d) -> Vec2d {
+        if Self::is_touching(tail, head) {
+            // tail and head are touching, so tail does not move
+            return *tail;
+        }
+        // move each coordinate of tail one step towards head
+        return *tail + (*head - *tail).signum();
+    }
+
+    /// Move the head of the rope one step in the given direction.
+    fn move_rope(&mut self, motion: Direction) {
+        let mut new_knots: Vec<Vec2d> = Vec::new();
+
+        // find new head position
+        new_knots.push(self.knots[0] + motion.to_vec());
+
+        // move all other knots
+        for i in 1..self.length {
+            new_knots.push(Self::move_towards(&self.knots[i], &new_knots[i - 1]));
+        }
+
+        self.knots = new_knots;
+    }
+
+    /// Returns list of tail positions after moving the rope according to the
+    /// given motions.
+    fn get_tail_movement(&mut self, motions: &[Direction]) -> Vec<Vec2d> {
+        let mut tail_positions: Vec<Vec2d> = Vec::new();
+
+        for motion in motions {
+            self.move_rope(*motion);
+            let tail = self.knots[self.length - 1];
+            tail_positions.push(tail);
+        }
+
+        return tail_positions;
+    }
+
+    /// Returns the positions of all knots after each step of the given
+    /// motions, starting with the current positions.
+    fn into_frames(mut self, motions: &[Direction]) -> impl Iterator<Item = Vec<Vec2d>> + '_ {
+        let initial = self.knots.clone();
+        std::iter::once(initial).chain(motions.iter().map(move |motion| {
+            self.move_rope(*motion);
+            self.knots.clone()
+        }))
+    }
+}
+
+/// Read all motions from file.
+fn get_motions(data: &str) -> Result<Vec<Direction>, AocError> {
+    let mut motions: Vec<Direction> = Vec::new();
+
+    for (motion, steps) in parse_lines(data, parse_motion)? {
+        for _ in 0..steps {
+            motions.push(motion);
+        }
+    }
+
+    return Ok(motions);
+}
+
+/// Parse a motion and its number of steps from a line like "R 4".
+fn parse_motion(line: &str) -> Result<(Direction, i32), AocError> {
+    let (direction, steps) = split_once(line, " ")?;
+    let motion = Direction::parse_letter(direction)?;
+    let steps: i32 = parse_num(steps)?;
+    return Ok((motion, steps));
+}
+
+fn count_unique_tail_positions(rope: &mut Rope, motions: &[Direction]) -> i32 {
+    let tail_positions = rope.get_tail_movement(motions);
+    let mut unique_tail_positions: HashSet<Vec2d> = HashSet::new();
+
+    let mut count = 0;
+    for tail_pos in tail_positions {
+        if unique_tail_positions.insert(tail_pos) {
+            count += 1;
+        }
+    }
+
+    return count;
+}
+
+/// Draw the knots of the rope on top of the positions visited by its tail.
+/// The head is drawn as 'H' and the other knots by their index.
+fn render_frame(knots: &[Vec2d], visited: &SparseGrid<bool>, bounds: Rect, color: bool) -> String {
+    let knot_cells = knots.iter().enumerate().map(|(i, &pos)| {
+        let cell = match i {
+            0 => Cell::new('H', Color::Red),
+            _ => Cell::new(char::from_digit(i as u32 % 36, 36).unwrap(), Color::Yellow),
+        };
+        (pos, cell)
+    });
+    let start = Cell::new('s', Color::Cyan);
+
+    Renderer::sparse(visited, |&v| {
+        if v {
+            Cell::new('#', Color::Gray)
+        } else {
+            Cell::from('.')
+        }
+    })
+    .bounds(bounds)
+    .overlay_points([(Vec2d::default(), start)])
+    .overlay_points(knot_cells)
+    .color(color)
+    .render()
+}
+
+/// Largest area drawn in each frame of an animation
+const MAX_VIEW_SIZE: Vec2d = Vec2d { x: 80, y: 40 };
+
+/// Returns the area to draw around the head of the rope, which is the whole
+/// area the rope moves over if it is small enough. Otherwise, the view follows
+/// the head without leaving that area.
+fn get_view(head: Vec2d, bounds: Rect) -> Rect {
+    let size = Vec2d {
+        x: bounds.width(),
+        y: bounds.height(),
+    }
+    .min(MAX_VIEW_SIZE);
+    let min = (head - size / 2).min(bounds.max - size).max(bounds.min);
+    return Rect::new(min, min + size);
+}
+
+/// Simulate a rope with the given number of knots, and return the rendered
+/// positions of its knots after each step, starting with the initial state.
+pub fn animate(motions: &[Direction], length: usize, color: bool) -> impl Iterator<Item = String> + '_ {
+    let bounds = Rect::from_points(Rope::new(length).into_frames(motions).flatten()).expand(1);
+
+    let mut visited = SparseGrid::new(false);
+    Rope::new(length).into_frames(motions).map(move |knots| {
+        visited.insert(knots[length - 1], true);
+        render_frame(&knots, &visited, get_view(knots[0], bounds), color)
+    })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Direction>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_motions(data)
+    }
+
+    fn part1(motions: &Self::Input) -> Result<impl Display, AocError> {
+        let mut rope = Rope::new(2);
+        let count = count_unique_tail_positions(&mut rope, motions);
+        return Ok(count);
+    }
+
+    fn part2(motions: &Self::Input) -> Result<impl Display, AocError> {
+        let mut rope = Rope::new(10);
+        let count = count_unique_tail_positions(&mut rope, motions);
+        return Ok(count);
+    }
+}
+
+/// Default number of knots in the animated rope
+const DEFAULT_ANIMATE_KNOTS: usize = 10;
+
+impl Animate for Day9 {
+    fn animate(motions: &Self::Input, options: AnimateOptions) -> impl Iterator<Item = String> + '_ {
+        animate(motions, options.knots.unwrap_or(DEFAULT_ANIMATE_KNOTS), options.color)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::AocError;
+use crate::parse::parse_lines;
+use crate::Solution;
+use std::fmt::Display;
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// An (index, value) pair of a digit in a line of text
+type IndexedDigit = (usize, u32);
+
+/// Given a line of text, get the (index, value) pairs for the first and last
+/// digits. Eg, for "abc123def", returns ((3, 1), (5, 3)).
+fn get_first_last_digits(s: &str) -> Result<(IndexedDigit, IndexedDigit), AocError> {
+    let mut index_digit_iter = s.chars().enumerate().filter(|(_, c)| c.is_ascii_digit()).peekable();
+    let first = *index_digit_iter
+        .peek()
+        .ok_or_else(|| AocError::invalid("Expected at least one digit", s))?;
+    let last = index_digit_iter.last().unwrap();
+    // convert `char` to `u32`
+    let first = (first.0, first.1.to_digit(10).unwrap());
+    let last = (last.0, last.1.to_digit(10).unwrap());
+    return Ok((first, last));
+}
+
+/// Given a line of text, get the (index, value) pairs for all words "one",
+/// "two", ..., "nine" found in the line.
+fn get_all_words(s: &str) -> Vec<(usize, u32)> {
+    let mut index_value_pairs: Vec<(usize, u32)> = Vec::new();
+    for (value, word) in WORDS.iter().enumerate() {
+        for (index, _) in s.match_indices(word) {
+            index_value_pairs.push((index, u32::try_from(value).unwrap()));
+        }
+    }
+    return index_value_pairs;
+}
+
+/// Parse the calibration value from a line of text. If `search_words` is true,
+/// then searches for words "one", "two", ..., "nine" in the line.
+fn parse_calibration_value(s: &str, search_words: bool) -> Result<u32, AocError> {
+    let (mut first, mut last) = get_first_last_digits(s)?;
+    if search_words {
+        for (index, value) in get_all_words(s) {
+            if index < first.0 {
+                first = (index, value);
+            }
+            if index > last.0 {
+                last = (index, value);
+            }
+        }
+    }
+    return Ok(first.1 * 10 + last.1);
+}
+
+/// Returns the lines of the calibration document
+fn get_lines(data: &str) -> Result<Vec<String>, AocError> {
+    parse_lines(data, |line| Ok(line.to_string()))
+}
+
+/// Sum the calibration values of all lines, attaching the line number to any
+/// error.
+fn sum_calibration_values(lines: &[String], search_words: bool) -> Result<u32, AocError> {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        sum += parse_calibration_value(line, search_words).map_err(|e| e.at_line(i + 1, line))?;
+    }
+    return Ok(sum);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_lines(data)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<impl Display, AocError> {
+        let sum = sum_calibration_values(lines, false)?;
+        return Ok(sum);
+    }
+
+    fn part2(lines: &Self::Input) -> Result<impl Display, AocError> {
+        let sum = sum_calibration_values(lines, true)?;
+        return Ok(sum);
+    }
+}
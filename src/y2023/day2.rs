@@ -1,10 +1,11 @@
+use crate::error::AocError;
+use crate::parse::{parse_lines, parse_num, split_once};
+use crate::Solution;
 use regex::Regex;
-
-/// File containing puzzle data
-const DATA: &'static str = "data/23day2.txt";
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: i32,
     cube_sets: Vec<CubeSet>,
 }
@@ -17,32 +18,30 @@ struct CubeSet {
 }
 
 /// Get all `Game`s from the input file
-fn get_games() -> Vec<Game> {
-    let data = std::fs::read_to_string(DATA).unwrap();
-    let games = data.split("\n").map(|line| parse_game(line)).collect();
-    return games;
+fn get_games(data: &str) -> Result<Vec<Game>, AocError> {
+    parse_lines(data, parse_game)
 }
 
 /// Parse the `Game` from a line of the input file
-fn parse_game(line: &str) -> Game {
+fn parse_game(line: &str) -> Result<Game, AocError> {
     // every line has the form "Game <number>: <cubes>; <cubes>; ..."
-    let mut iter = line.split(":");
-    let game_id = parse_game_id(iter.next().unwrap());
-    let cube_sets = parse_cube_sets(iter.next().unwrap());
-    return Game {
-        id: game_id,
-        cube_sets: cube_sets,
-    };
+    let (game, cubes) = split_once(line, ":")?;
+    let game_id = parse_game_id(game)?;
+    let cube_sets = parse_cube_sets(cubes)?;
+    return Ok(Game { id: game_id, cube_sets });
 }
 
-fn parse_game_id(s: &str) -> i32 {
+fn parse_game_id(s: &str) -> Result<i32, AocError> {
     // `s` has the form "Game <number>"
     let re = Regex::new(r"Game ([0-9]+)").unwrap();
-    let game_id: i32 = re.captures(s).unwrap()[1].parse().unwrap();
-    return game_id;
+    let cap = re
+        .captures(s)
+        .ok_or_else(|| AocError::invalid("Expected \"Game <number>\"", s))?;
+    let game_id: i32 = parse_num(&cap[1])?;
+    return Ok(game_id);
 }
 
-fn parse_cube_sets(s: &str) -> Vec<CubeSet> {
+fn parse_cube_sets(s: &str) -> Result<Vec<CubeSet>, AocError> {
     // `s` has the form "<cubes>; <cubes>; ..."
     let mut cube_sets: Vec<CubeSet> = Vec::new();
 
@@ -52,22 +51,22 @@ fn parse_cube_sets(s: &str) -> Vec<CubeSet> {
 
     for part in s.split(";") {
         let red: i32 = match red_re.captures(part) {
-            Some(cap) => cap[1].parse().unwrap(),
+            Some(cap) => parse_num(&cap[1])?,
             None => 0,
         };
         let green: i32 = match green_re.captures(part) {
-            Some(cap) => cap[1].parse().unwrap(),
+            Some(cap) => parse_num(&cap[1])?,
             None => 0,
         };
         let blue: i32 = match blue_re.captures(part) {
-            Some(cap) => cap[1].parse().unwrap(),
+            Some(cap) => parse_num(&cap[1])?,
             None => 0,
         };
 
         let cube_set = CubeSet { red, green, blue };
         cube_sets.push(cube_set);
     }
-    return cube_sets;
+    return Ok(cube_sets);
 }
 
 fn compute_power(game: &Game) -> i32 {
@@ -94,11 +93,16 @@ fn compute_power(game: &Game) -> i32 {
     return power;
 }
 
-fn main() {
-    let games = get_games();
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_games(data)
+    }
 
-    // Part 1
-    {
+    fn part1(games: &Self::Input) -> Result<impl Display, AocError> {
         // number of cubes in the bag
         let num_red = 12;
         let num_green = 13;
@@ -106,7 +110,7 @@ fn main() {
 
         let mut sum_id = 0;
 
-        'outer: for game in &games {
+        'outer: for game in games {
             for cube_set in &game.cube_sets {
                 // check if cube set is possible
                 if cube_set.red > num_red || cube_set.green > num_green || cube_set.blue > num_blue {
@@ -117,12 +121,11 @@ fn main() {
             sum_id += game.id;
         }
 
-        println!("Part 1: {}", sum_id); // Expected output: 3099
+        return Ok(sum_id);
     }
 
-    // Part 2
-    {
-        let sum_powers: i32 = games.iter().map(|game| compute_power(game)).sum();
-        println!("Part 2: {}", sum_powers); // Expected output: 72970
+    fn part2(games: &Self::Input) -> Result<impl Display, AocError> {
+        let sum_powers: i32 = games.iter().map(compute_power).sum();
+        return Ok(sum_powers);
     }
 }
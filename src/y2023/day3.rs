@@ -0,0 +1,150 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::parse::parse_num;
+use crate::vec::Vec2d;
+use crate::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// A number in the engine, labelled by its coordinates and value. Note that a
+/// "number" may not necessary be a "part".
+#[derive(Debug)]
+struct Number {
+    row: usize,
+    col: usize,
+    length: usize,
+    value: i32,
+}
+
+/// A symbol in the schematic, labelled by its coordinates and value.
+#[derive(Debug)]
+struct Symbol {
+    row: usize,
+    col: usize,
+    value: char,
+}
+
+/// Read schematic from the text file
+fn get_schematic(data: &str) -> Result<Grid<char>, AocError> {
+    Grid::parse(data, |c| {
+        if !c.is_ascii_graphic() {
+            return Err(AocError::invalid("Invalid character", &c.to_string()));
+        }
+        return Ok(c);
+    })
+}
+
+/// Given a schematic, returns a list of all numbers in the schematic
+fn parse_numbers(schematic: &Grid<char>) -> Result<Vec<Number>, AocError> {
+    let mut numbers: Vec<Number> = Vec::new();
+    let re = Regex::new(r"([0-9]+)").unwrap();
+    for (row, cells) in schematic.rows().enumerate() {
+        let line: String = cells.iter().collect();
+        for cap in re.captures_iter(&line) {
+            let mat = cap.get(1).unwrap();
+            let number = Number {
+                row,
+                col: mat.start(),
+                length: mat.end() - mat.start(),
+                value: parse_num(&cap[1]).map_err(|e| e.at_line(row + 1, &line))?,
+            };
+            numbers.push(number);
+        }
+    }
+    return Ok(numbers);
+}
+
+/// Given a number in a schematic, check if it is a part. If it is, returns
+/// the adjacent symbol and its coordinates. Otherwise, returns None.
+fn is_part(number: &Number, schematic: &Grid<char>) -> Option<Symbol> {
+    // Returns the symbol at the coordinate, if any. The given coordinate can
+    // be out of bounds.
+    let get_symbol = |pos: Vec2d| -> Option<Symbol> {
+        let (col, row) = pos.to_index(schematic.width(), schematic.height())?;
+        match schematic[pos] {
+            '.' => None,
+            value => Some(Symbol { row, col, value }),
+        }
+    };
+
+    let start = Vec2d {
+        x: i32::try_from(number.col).unwrap(),
+        y: i32::try_from(number.row).unwrap(),
+    };
+    let length = i32::try_from(number.length).unwrap();
+
+    // check directly left and right
+    if let Some(s) = get_symbol(start - Vec2d { x: 1, y: 0 }) {
+        return Some(s);
+    }
+    if let Some(s) = get_symbol(start + Vec2d { x: length, y: 0 }) {
+        return Some(s);
+    }
+    // check rows above and below
+    for x in (start.x - 1)..(start.x + length + 1) {
+        if let Some(s) = get_symbol(Vec2d { x, y: start.y - 1 }) {
+            return Some(s);
+        }
+        if let Some(s) = get_symbol(Vec2d { x, y: start.y + 1 }) {
+            return Some(s);
+        }
+    }
+    return None;
+}
+
+/// The engine schematic, along with all numbers found in it
+pub struct Engine {
+    schematic: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Engine;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let schematic = get_schematic(data)?;
+        let numbers = parse_numbers(&schematic)?;
+        return Ok(Engine { schematic, numbers });
+    }
+
+    fn part1(engine: &Self::Input) -> Result<impl Display, AocError> {
+        let mut part_number_sum = 0;
+        for number in &engine.numbers {
+            // the number is a part if it is adjacent to a symbol
+            if is_part(number, &engine.schematic).is_some() {
+                part_number_sum += number.value;
+            }
+        }
+        return Ok(part_number_sum);
+    }
+
+    fn part2(engine: &Self::Input) -> Result<impl Display, AocError> {
+        // we collect a hash map of all encountered gear symbols. the key is
+        // (row, col) and the value is a list of part numbers adjacent to the
+        // gear symbol.
+        let mut gear_to_numbers: HashMap<(usize, usize), Vec<i32>> = HashMap::new();
+
+        for number in &engine.numbers {
+            if let Some(symbol) = is_part(number, &engine.schematic) {
+                // `number` is a part
+                if symbol.value == '*' {
+                    gear_to_numbers
+                        .entry((symbol.row, symbol.col))
+                        .or_default()
+                        .push(number.value);
+                }
+            }
+        }
+
+        // now we iterate over all gears and filter for all those with exactly two adjacent parts
+        let sum_gear_ratios: i32 = gear_to_numbers
+            .values()
+            .filter(|part_numbers| part_numbers.len() == 2)
+            .map(|part_numbers| part_numbers.iter().product::<i32>())
+            .sum();
+        return Ok(sum_gear_ratios);
+    }
+}
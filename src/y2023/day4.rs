@@ -0,0 +1,91 @@
+use crate::error::AocError;
+use crate::parse::{parse_lines, parse_num, split_once};
+use crate::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// A card containing (winning numbers, my numbers)
+type Card = (Vec<i32>, Vec<i32>);
+
+/// Given a string of whitespace-separated numbers, e.g. "45 12  3", returns a
+/// vector of those numbers.
+fn parse_whitespace_separated_numbers(s: &str) -> Result<Vec<i32>, AocError> {
+    s.split_whitespace().map(parse_num).collect()
+}
+
+/// Return list of cards containing (winning numbers, my numbers) pairs.
+fn get_cards(data: &str) -> Result<Vec<Card>, AocError> {
+    parse_lines(data, |line| {
+        // line is of the form: "Card #: # # # ... | # # # ..."
+        let (_, line_numbers) = split_once(line, ":")?;
+        // line_numbers is of the form: "# # # ... | # # # ..."
+        let (winning, my) = split_once(line_numbers, "|")?;
+        let winning = parse_whitespace_separated_numbers(winning)?;
+        let my = parse_whitespace_separated_numbers(my)?;
+        return Ok((winning, my));
+    })
+}
+
+fn count_matches(winning: &[i32], my: &[i32]) -> usize {
+    // put winning numbers to a set
+    let mut winning_set = HashSet::new();
+    for &num in winning.iter() {
+        winning_set.insert(num);
+    }
+    // count number of matches
+    let matches = my.iter().filter(|num| winning_set.contains(num)).count();
+    return matches;
+}
+
+fn compute_score(matches: usize) -> i32 {
+    if matches == 0 {
+        return 0;
+    } else {
+        return i32::pow(2, (matches - 1) as u32);
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        get_cards(data)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<impl Display, AocError> {
+        let total_score: i32 = cards.iter().map(|(w, o)| count_matches(w, o)).map(compute_score).sum();
+        return Ok(total_score);
+    }
+
+    fn part2(cards: &Self::Input) -> Result<impl Display, AocError> {
+        // start with vector of number of copies of each card
+        let mut copies: Vec<i32> = vec![1; cards.len()];
+
+        for (card_idx, card) in cards.iter().enumerate() {
+            let matches = count_matches(&card.0, &card.1);
+
+            let num_copies = copies[card_idx];
+            for i in 0..matches {
+                let new_card_idx = card_idx + i + 1;
+                if new_card_idx >= cards.len() {
+                    // each card is on its own line, but its text is not kept
+                    return Err(AocError {
+                        line: Some(card_idx + 1),
+                        ..AocError::new(format!(
+                            "Card {} wins a copy of card {}, but there are only {} cards",
+                            card_idx + 1,
+                            new_card_idx + 1,
+                            cards.len()
+                        ))
+                    });
+                }
+                copies[new_card_idx] += num_copies;
+            }
+        }
+
+        let total_num_cards: i32 = copies.iter().sum();
+        return Ok(total_num_cards);
+    }
+}